@@ -0,0 +1,46 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "parentheses"))?;
+
+        line.text
+            .trim_end()
+            .char_indices()
+            .map(|(index, char)| match char {
+                '(' => Ok(1),
+                ')' => Ok(-1),
+                _ => Err(line.error_at_char(index, "`(` or `)`")),
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Self::Input) -> impl Display {
+        moves.iter().sum::<i32>()
+    }
+
+    fn part2(moves: &Self::Input) -> impl Display {
+        MaybeAnswer(get_basement_position(moves))
+    }
+}
+
+/// Position, from 1, of the first move entering the basement.
+fn get_basement_position(moves: &[i32]) -> Option<usize> {
+    let mut floor: i32 = 0;
+    for (index, floor_move) in moves.iter().enumerate() {
+        floor += floor_move;
+
+        if floor < 0 {
+            return Some(index + 1);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,60 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::cmp::min;
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(u32, u32, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input).map(parse_dimension).collect()
+    }
+
+    fn part1(dimensions: &Self::Input) -> impl Display {
+        dimensions
+            .iter()
+            .map(|&(l, w, h)| get_paper_area(l, w, h))
+            .sum::<u32>()
+    }
+
+    fn part2(dimensions: &Self::Input) -> impl Display {
+        dimensions
+            .iter()
+            .map(|&(l, w, h)| get_ribbon_length(l, w, h))
+            .sum::<u32>()
+    }
+}
+
+fn parse_dimension(line: parse::Line) -> Result<(u32, u32, u32), ParseError> {
+    let dimensions = line
+        .text
+        .split('x')
+        .map(|dimension| line.parse::<u32>(dimension, "an integer"))
+        .collect::<Result<Vec<u32>, ParseError>>()?;
+
+    match dimensions[..] {
+        [l, w, h] => Ok((l, w, h)),
+        _ => Err(line.error("three dimensions like `2x3x4`")),
+    }
+}
+
+fn get_paper_area(l: u32, w: u32, h: u32) -> u32 {
+    let area_lw = l * w;
+    let area_lh = l * h;
+    let area_wh = w * h;
+
+    let min_area = min(min(area_lw, area_lh), area_wh);
+
+    2 * (area_lw + area_lh + area_wh) + min_area
+}
+
+fn get_ribbon_length(l: u32, w: u32, h: u32) -> u32 {
+    let ribbon_lw = l + w;
+    let ribbon_lh = l + h;
+    let ribbon_wh = w + h;
+
+    let min_ribbon = min(min(ribbon_lw, ribbon_lh), ribbon_wh);
+
+    2 * min_ribbon + l * w * h
+}
@@ -0,0 +1,63 @@
+use aoc_core::{parse, Dir4, ParseError, Point2, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Dir4>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "moves"))?;
+
+        line.text
+            .trim_end()
+            .char_indices()
+            .map(|(index, char)| match char {
+                '<' => Ok(Dir4::Left),
+                '>' => Ok(Dir4::Right),
+                'v' => Ok(Dir4::Down),
+                '^' => Ok(Dir4::Up),
+                _ => Err(line.error_at_char(index, "one of `<`, `>`, `v` or `^`")),
+            })
+            .collect()
+    }
+
+    fn part1(orders: &Self::Input) -> impl Display {
+        let mut current_santa_position = Point2::new(0, 0);
+        let mut positions = HashSet::new();
+        positions.insert(current_santa_position);
+
+        for order in orders.iter() {
+            current_santa_position = current_santa_position.step(*order);
+            positions.insert(current_santa_position);
+        }
+
+        positions.len()
+    }
+
+    fn part2(orders: &Self::Input) -> impl Display {
+        let mut current_santa_position = Point2::new(0, 0);
+        let mut current_robot_position = Point2::new(0, 0);
+        let mut positions = HashSet::new();
+        positions.insert(current_santa_position);
+
+        let mut is_santa_turn = true;
+
+        for order in orders.iter() {
+            if is_santa_turn {
+                current_santa_position = current_santa_position.step(*order);
+                positions.insert(current_santa_position);
+                is_santa_turn = false;
+            } else {
+                current_robot_position = current_robot_position.step(*order);
+                positions.insert(current_robot_position);
+                is_santa_turn = true;
+            }
+        }
+
+        positions.len()
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_core::{MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        match input.trim() {
+            "" => Err(ParseError::at_end(input, "a secret key")),
+            secret_key => Ok(String::from(secret_key)),
+        }
+    }
+
+    fn part1(secret_key: &Self::Input) -> impl Display {
+        MaybeAnswer(find_number_to_stick(secret_key, 5))
+    }
+
+    fn part2(secret_key: &Self::Input) -> impl Display {
+        MaybeAnswer(find_number_to_stick(secret_key, 6))
+    }
+}
+
+fn find_number_to_stick(secret_key: &str, zero_count: usize) -> Option<usize> {
+    (0..usize::MAX).find(|number_to_stick| {
+        let combination = format!("{secret_key}{number_to_stick}");
+        let hash_string = format!("{:?}", md5::compute(combination));
+
+        hash_string.chars().take(zero_count).all(|char| char == '0')
+    })
+}
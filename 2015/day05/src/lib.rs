@@ -0,0 +1,157 @@
+use aoc_core::{parse, ParseError, Solution};
+use log::trace;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+const FORBIDDEN_PAIRS: [&str; 4] = ["ab", "cd", "pq", "xy"];
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(
+                |line| match line.text.find(|char: char| !char.is_ascii_lowercase()) {
+                    Some(index) => Err(line.error_at_char(index, "a lowercase letter")),
+                    None if line.text.is_empty() => Err(line.error("a word")),
+                    None => Ok(String::from(line.text)),
+                },
+            )
+            .collect()
+    }
+
+    fn part1(words: &Self::Input) -> impl Display {
+        words.iter().filter(|word| check_old_word(word)).count()
+    }
+
+    fn part2(words: &Self::Input) -> impl Display {
+        let mut good_word_count: usize = 0;
+
+        for word in words.iter() {
+            if check_word(word) {
+                trace!("Nice word {word}");
+                good_word_count += 1;
+            };
+        }
+        good_word_count
+    }
+}
+
+fn check_old_word(word: &str) -> bool {
+    let vowel_count = word
+        .chars()
+        .filter(|letter| VOWELS.contains(letter))
+        .count();
+    let has_double_letter = word
+        .as_bytes()
+        .windows(2)
+        .any(|letters| letters[0] == letters[1]);
+    let has_forbidden_pair = FORBIDDEN_PAIRS.iter().any(|pair| word.contains(pair));
+
+    vowel_count >= 3 && has_double_letter && !has_forbidden_pair
+}
+
+fn check_word(word: &str) -> bool {
+    check_double_pair(word) && check_sandwich(word)
+}
+
+fn check_double_pair(word: &str) -> bool {
+    let mut word_iter = word.chars().enumerate();
+    let mut already_seen: HashMap<[char; 2], usize> = HashMap::new();
+    let (_index, mut previous_letter) = word_iter.next().expect("the first letter");
+    for (index, letter) in word_iter {
+        let letter_pair = [previous_letter, letter];
+        if let Some(&last_index) = already_seen.get(&letter_pair) {
+            if last_index + 1 < index {
+                return true;
+            }
+            continue;
+        }
+        already_seen.insert(letter_pair, index);
+        previous_letter = letter;
+    }
+    false
+}
+
+fn check_sandwich(word: &str) -> bool {
+    let mut word_iter = word.chars();
+    let mut previous_previous_letter = word_iter.next().expect("the first letter");
+    let mut previous_letter = word_iter.next().expect("the second letter");
+
+    for letter in word_iter {
+        if letter == previous_previous_letter {
+            return true;
+        }
+        previous_previous_letter = previous_letter;
+        previous_letter = letter;
+    }
+    false
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_old_word() {
+        assert!(check_old_word("ugknbfddgicrmopn"));
+        assert!(check_old_word("aaa"));
+        assert!(!check_old_word("jchzalrnumimnmhp"));
+        assert!(!check_old_word("haegwjzuvuyypxyu"));
+        assert!(!check_old_word("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn test_double_double_overlap() {
+        assert_eq!(check_double_pair("aaabcddd"), false);
+    }
+
+    #[test]
+    fn test_double_double() {
+        assert_eq!(check_double_pair("abbcddaab"), true);
+    }
+
+    #[test]
+    fn test_double_double_row() {
+        assert_eq!(check_double_pair("aaaa"), true);
+    }
+
+    #[test]
+    fn test_sandwich_1() {
+        assert_eq!(check_sandwich("xyx"), true);
+    }
+
+    #[test]
+    fn test_sandwich_2() {
+        assert_eq!(check_sandwich("xyz"), false);
+    }
+
+    #[test]
+    fn test_sandwich_3() {
+        assert_eq!(check_sandwich("aaa"), true);
+    }
+
+    #[test]
+    fn test_all_1() {
+        assert_eq!(check_double_pair("qjhvhtzxzqqjkmpb"), true);
+    }
+
+    #[test]
+    fn test_all_2() {
+        assert_eq!(check_word("xxyxx"), true);
+    }
+
+    #[test]
+    fn test_all_3() {
+        assert_eq!(check_word("uurcxstgmygtbstg"), false);
+    }
+
+    #[test]
+    fn test_all_4() {
+        assert_eq!(check_word("ieodomkazucvgmuy"), false);
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_core::progress::ProgressIterator;
+use aoc_core::{parse, ParseError, Pattern, Solution};
+use itertools::Itertools;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+enum Order {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Debug)]
+pub struct Command {
+    order: Order,
+    start_x: usize,
+    start_y: usize,
+    end_x: usize,
+    end_y: usize,
+}
+
+const MAX_X: usize = 999;
+const MAX_Y: usize = 999;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_commands(input)
+    }
+
+    fn part1(commands: &Self::Input) -> impl Display {
+        (0..MAX_X + 1)
+            .cartesian_product(0..MAX_Y + 1)
+            .progress("lights")
+            .filter(|&(x, y)| is_position_lit(x, y, commands))
+            .count()
+    }
+
+    fn part2(commands: &Self::Input) -> impl Display {
+        let mut total_brightness: usize = 0;
+
+        for (x, y) in (0..MAX_X + 1)
+            .cartesian_product(0..MAX_Y + 1)
+            .progress("lights")
+        {
+            total_brightness += get_position_brightness(x, y, commands);
+        }
+
+        total_brightness
+    }
+}
+
+fn is_position_lit(x: usize, y: usize, commands: &[Command]) -> bool {
+    let mut is_lit = false;
+
+    for command in commands.iter() {
+        if !is_inside_command(x, y, command) {
+            continue;
+        };
+        is_lit = match command.order {
+            Order::TurnOn => true,
+            Order::Toggle => !is_lit,
+            Order::TurnOff => false,
+        }
+    }
+
+    is_lit
+}
+
+fn get_position_brightness(x: usize, y: usize, commands: &[Command]) -> usize {
+    let mut brightness = 0;
+
+    for command in commands.iter() {
+        if !is_inside_command(x, y, command) {
+            continue;
+        };
+        brightness = match command.order {
+            Order::TurnOn => brightness + 1,
+            Order::Toggle => brightness + 2,
+            Order::TurnOff => usize::max(1, brightness) - 1,
+        }
+    }
+
+    brightness
+}
+
+fn is_inside_command(x: usize, y: usize, command: &Command) -> bool {
+    command.start_x <= x && x <= command.end_x && command.start_y <= y && y <= command.end_y
+}
+
+/// Order and corners of a command, as scanned.
+type CommandFields<'a> = (&'a str, usize, usize, usize, usize);
+
+fn parse_command<'a>(
+    line: parse::Line<'a>,
+    pattern: &Pattern<CommandFields<'a>>,
+) -> Result<Command, ParseError> {
+    let (order, start_x, start_y, end_x, end_y) = line.scan(pattern)?;
+    let order = match order {
+        "turn on" => Order::TurnOn,
+        "turn off" => Order::TurnOff,
+        "toggle" => Order::Toggle,
+        order => return Err(line.error_at(order, "`turn on`, `turn off` or `toggle`")),
+    };
+
+    Ok(Command {
+        order,
+        start_x,
+        start_y,
+        end_x,
+        end_y,
+    })
+}
+
+fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    let pattern = Pattern::new("{order} {usize},{usize} through {usize},{usize}").unwrap();
+    parse::lines(input)
+        .map(|line| parse_command(line, &pattern))
+        .collect()
+}
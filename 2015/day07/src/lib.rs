@@ -0,0 +1,270 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+pub enum OneOperandOperation {
+    Constant,
+    Not,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TwoOperandOperation {
+    And,
+    Or,
+    RShift,
+    LShift,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Operand {
+    Constant(u16),
+    GateId(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Gate {
+    OneOperandGate(OneOperandOperation, Operand),
+    TwoOperandGate(TwoOperandOperation, Operand, Operand),
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Gate>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_gates(input)
+    }
+
+    fn part1(gates: &Self::Input) -> impl Display {
+        let mut gate_values: HashMap<String, u16> = HashMap::new();
+        compute_gate_id("a", gates, &mut gate_values)
+    }
+
+    fn part2(gates: &Self::Input) -> impl Display {
+        let mut gate_values: HashMap<String, u16> = HashMap::new();
+        let a_value = compute_gate_id("a", gates, &mut gate_values);
+
+        let mut gate_values: HashMap<String, u16> = HashMap::from([(String::from("b"), a_value)]);
+        compute_gate_id("a", gates, &mut gate_values)
+    }
+}
+
+fn parse_operand(operand: &str) -> Operand {
+    if let Ok(constant) = operand.parse::<u16>() {
+        Operand::Constant(constant)
+    } else {
+        Operand::GateId(String::from(operand))
+    }
+}
+
+fn parse_gate(line: parse::Line) -> Result<(String, Gate), ParseError> {
+    let Some((operation_formula, gate_id)) = line.text.split_once(" -> ") else {
+        return Err(line.error("a gate like `x AND y -> z`"));
+    };
+
+    let operation_parts = operation_formula.split(' ').collect::<Vec<&str>>();
+
+    let gate = match operation_parts[..] {
+        [operand] => Gate::OneOperandGate(OneOperandOperation::Constant, parse_operand(operand)),
+        ["NOT", operand] => Gate::OneOperandGate(OneOperandOperation::Not, parse_operand(operand)),
+        [operator, _] => return Err(line.error_at(operator, "`NOT`")),
+        [operand_1, operator, operand_2] => {
+            let operation = match operator {
+                "AND" => TwoOperandOperation::And,
+                "OR" => TwoOperandOperation::Or,
+                "LSHIFT" => TwoOperandOperation::LShift,
+                "RSHIFT" => TwoOperandOperation::RShift,
+                _ => {
+                    return Err(line.error_at(operator, "`AND`, `OR`, `LSHIFT` or `RSHIFT`"));
+                }
+            };
+            Gate::TwoOperandGate(
+                operation,
+                parse_operand(operand_1),
+                parse_operand(operand_2),
+            )
+        }
+        _ => {
+            return Err(line.error_at(operation_formula, "an operation with one or two operands"));
+        }
+    };
+
+    Ok((String::from(gate_id), gate))
+}
+
+/// Every wire read by a gate, and the wire `a`, must be driven by a gate.
+fn parse_gates(input: &str) -> Result<HashMap<String, Gate>, ParseError> {
+    let lines: Vec<parse::Line> = parse::lines(input).collect();
+    let gates = lines
+        .iter()
+        .map(|&line| parse_gate(line))
+        .collect::<Result<HashMap<String, Gate>, ParseError>>()?;
+
+    for line in lines.iter() {
+        let (operation_formula, _) = line.text.split_once(" -> ").unwrap();
+        if let Some(wire) = operation_formula.split(' ').find(|operand| {
+            operand.chars().all(|char| char.is_ascii_lowercase()) && !gates.contains_key(*operand)
+        }) {
+            return Err(line.error_at(wire, "a wire driven by a gate"));
+        }
+    }
+    if !gates.contains_key("a") {
+        return Err(ParseError::at_end(input, "a gate driving the wire `a`"));
+    }
+
+    Ok(gates)
+}
+
+fn compute_gate_id(
+    gate_id: &str,
+    gates: &HashMap<String, Gate>,
+    gate_values: &mut HashMap<String, u16>,
+) -> u16 {
+    if let Some(value) = gate_values.get(gate_id) {
+        return *value;
+    }
+
+    let gate = gates.get(gate_id).expect("to get gate");
+
+    let value = match gate {
+        Gate::OneOperandGate(OneOperandOperation::Constant, operand) => {
+            get_operand_value(operand, gates, gate_values)
+        }
+        Gate::OneOperandGate(OneOperandOperation::Not, operand) => {
+            !get_operand_value(operand, gates, gate_values)
+        }
+        Gate::TwoOperandGate(TwoOperandOperation::And, operand_1, operand_2) => {
+            get_operand_value(operand_1, gates, gate_values)
+                & get_operand_value(operand_2, gates, gate_values)
+        }
+        Gate::TwoOperandGate(TwoOperandOperation::Or, operand_1, operand_2) => {
+            get_operand_value(operand_1, gates, gate_values)
+                | get_operand_value(operand_2, gates, gate_values)
+        }
+        Gate::TwoOperandGate(TwoOperandOperation::LShift, operand_1, operand_2) => {
+            get_operand_value(operand_1, gates, gate_values)
+                << get_operand_value(operand_2, gates, gate_values)
+        }
+        Gate::TwoOperandGate(TwoOperandOperation::RShift, operand_1, operand_2) => {
+            get_operand_value(operand_1, gates, gate_values)
+                >> get_operand_value(operand_2, gates, gate_values)
+        }
+    };
+
+    gate_values.insert(String::from(gate_id), value);
+    value
+}
+
+fn get_operand_value(
+    operand: &Operand,
+    gates: &HashMap<String, Gate>,
+    gate_values: &mut HashMap<String, u16>,
+) -> u16 {
+    match operand {
+        Operand::Constant(constant) => *constant,
+        Operand::GateId(gate_id) => compute_gate_id(gate_id, gates, gate_values),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> parse::Line<'_> {
+        parse::Line { number: 1, text }
+    }
+
+    #[test]
+    fn test_constant_gate() {
+        assert_eq!(
+            parse_gate(line("14146 -> ab")).unwrap(),
+            (
+                String::from("ab"),
+                Gate::OneOperandGate(OneOperandOperation::Constant, Operand::Constant(14146))
+            )
+        );
+    }
+
+    #[test]
+    fn test_not_gate() {
+        assert_eq!(
+            parse_gate(line("NOT az -> ab")).unwrap(),
+            (
+                String::from("ab"),
+                Gate::OneOperandGate(
+                    OneOperandOperation::Not,
+                    Operand::GateId(String::from("az"))
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_and_gate() {
+        assert_eq!(
+            parse_gate(line("ad AND az -> ab")).unwrap(),
+            (
+                String::from("ab"),
+                Gate::TwoOperandGate(
+                    TwoOperandOperation::And,
+                    Operand::GateId(String::from("ad")),
+                    Operand::GateId(String::from("az"))
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_and_gate_with_constant() {
+        assert_eq!(
+            parse_gate(line("14 AND 12 -> ab")).unwrap(),
+            (
+                String::from("ab"),
+                Gate::TwoOperandGate(
+                    TwoOperandOperation::And,
+                    Operand::Constant(14),
+                    Operand::Constant(12)
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_lshift_gate() {
+        assert_eq!(
+            parse_gate(line("ad LSHIFT 3 -> ab")).unwrap(),
+            (
+                String::from("ab"),
+                Gate::TwoOperandGate(
+                    TwoOperandOperation::LShift,
+                    Operand::GateId(String::from("ad")),
+                    Operand::Constant(3)
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_unknown_operator() {
+        let error = parse_gate(line("ad XOR az -> ab")).unwrap_err();
+        assert_eq!((error.column, error.length), (4, 3));
+    }
+
+    #[test]
+    fn test_undriven_wire() {
+        let error = parse_gates("123 -> x\nx AND y -> a").unwrap_err();
+        assert_eq!((error.line_number, error.column, error.length), (2, 7, 1));
+    }
+
+    #[test]
+    fn test_parse_operand_constant() {
+        assert_eq!(parse_operand("12"), Operand::Constant(12));
+    }
+
+    #[test]
+    fn test_parse_operand_gate() {
+        assert_eq!(parse_operand("ab"), Operand::GateId(String::from("ab")));
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_core::{combinatorics, parse, MaybeAnswer, ParseError, Pattern, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+type Graph = HashMap<String, HashMap<String, u32>>;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_distances(input)
+    }
+
+    fn part1(distances_graph: &Self::Input) -> impl Display {
+        MaybeAnswer(get_extremum_distance(distances_graph, u32::min))
+    }
+
+    fn part2(distances_graph: &Self::Input) -> impl Display {
+        MaybeAnswer(get_extremum_distance(distances_graph, u32::max))
+    }
+}
+
+fn parse_distances(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = HashMap::new();
+    let pattern = Pattern::new("{location} to {location} = {u32}").unwrap();
+
+    for line in parse::lines(input) {
+        let (point_1, point_2, distance): (String, String, u32) = line.scan(&pattern)?;
+
+        insert_distance_into_graph(&point_1, &point_2, distance, &mut graph);
+        insert_distance_into_graph(&point_2, &point_1, distance, &mut graph);
+    }
+
+    Ok(graph)
+}
+
+fn insert_distance_into_graph(from: &str, to: &str, distance: u32, graph: &mut Graph) {
+    graph
+        .entry(String::from(from))
+        .or_default()
+        .insert(String::from(to), distance);
+}
+
+/// Shortest or longest route visiting every location, depending on `select`. Routes only go
+/// between locations whose distance is known.
+fn get_extremum_distance(graph: &Graph, select: fn(u32, u32) -> u32) -> Option<u32> {
+    combinatorics::distinct_permutations(graph.keys())
+        .filter_map(|route| {
+            route
+                .windows(2)
+                .map(|pair| graph[pair[0]].get(pair[1]).copied())
+                .sum::<Option<u32>>()
+        })
+        .reduce(select)
+}
@@ -0,0 +1,65 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "a sequence of digits"))?;
+        let sequence = line.text.trim_end();
+
+        match sequence.find(|char: char| !char.is_ascii_digit()) {
+            Some(index) => Err(line.error_at_char(index, "a digit")),
+            None if sequence.is_empty() => Err(line.error("a sequence of digits")),
+            None => Ok(String::from(sequence)),
+        }
+    }
+
+    fn part1(sequence: &Self::Input) -> impl Display {
+        get_look_and_say_length(sequence, 40)
+    }
+
+    fn part2(sequence: &Self::Input) -> impl Display {
+        get_look_and_say_length(sequence, 50)
+    }
+}
+
+fn get_look_and_say_length(sequence: &str, iteration_count: usize) -> usize {
+    let mut sequence = String::from(sequence);
+
+    for _ in 0..iteration_count {
+        sequence = look_and_say(sequence);
+    }
+
+    sequence.len()
+}
+
+/// Reads each run of the same digit aloud, its length being written in full even past 9.
+fn look_and_say(current: String) -> String {
+    let mut next = String::new();
+    let mut chars = current.chars();
+
+    let mut counted_char = chars.next().expect("at least one char");
+    let mut char_count = 1;
+
+    for current_char in chars {
+        if current_char == counted_char {
+            char_count += 1;
+            continue;
+        }
+
+        next.push_str(&char_count.to_string());
+        next.push(counted_char);
+
+        counted_char = current_char;
+        char_count = 1;
+    }
+    next.push_str(&char_count.to_string());
+    next.push(counted_char);
+
+    next
+}
@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use aoc_core::{parse, ParseError, Solution};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    #[derive(Debug)]
+    static ref NEXT_LETTER: HashMap<char, char> = HashMap::from([
+        ('a', 'b'),
+        ('b', 'c'),
+        ('c', 'd'),
+        ('d', 'e'),
+        ('e', 'f'),
+        ('f', 'g'),
+        ('g', 'h'),
+        ('h', 'j'),
+        ('j', 'k'),
+        ('k', 'm'),
+        ('m', 'n'),
+        ('n', 'p'),
+        ('p', 'q'),
+        ('q', 'r'),
+        ('r', 's'),
+        ('s', 't'),
+        ('t', 'u'),
+        ('u', 'v'),
+        ('v', 'w'),
+        ('w', 'x'),
+        ('x', 'y'),
+        ('y', 'z'),
+        ('z', 'a'),
+    ]);
+
+    static ref ALL_INCREASING: HashSet<String> = HashSet::from([
+        String::from("abc"),
+        String::from("bcd"),
+        String::from("cde"),
+        String::from("def"),
+        String::from("efg"),
+        String::from("fgh"),
+        String::from("pqr"),
+        String::from("qrs"),
+        String::from("rst"),
+        String::from("stu"),
+        String::from("tuv"),
+        String::from("uvw"),
+        String::from("vwx"),
+        String::from("wxy"),
+        String::from("xyz"),
+    ]);
+}
+
+const PASSWORD_LENGTH: usize = 8;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "a password"))?;
+        let password = line.text.trim_end();
+
+        // Passwords never hold the confusing letters, which have no next letter.
+        match password.find(|char: char| !NEXT_LETTER.contains_key(&char)) {
+            Some(index) => {
+                Err(line.error_at_char(index, "a lowercase letter other than i, o and l"))
+            }
+            None if password.len() != PASSWORD_LENGTH => {
+                Err(line.error(format!("a password of {PASSWORD_LENGTH} letters")))
+            }
+            None => Ok(String::from(password)),
+        }
+    }
+
+    fn part1(password: &Self::Input) -> impl Display {
+        get_next_valid_password(password.clone())
+    }
+
+    fn part2(password: &Self::Input) -> impl Display {
+        get_next_valid_password(get_next_valid_password(password.clone()))
+    }
+}
+
+fn get_next_valid_password(mut password: String) -> String {
+    password = get_next_password(password);
+
+    while !is_password_valid(&password) {
+        password = get_next_password(password);
+    }
+    password
+}
+
+fn is_password_valid(password: &str) -> bool {
+    has_increasing(password) && has_multiple_pairs(password)
+}
+
+fn has_increasing(password: &str) -> bool {
+    for increasing in ALL_INCREASING.iter() {
+        if password.contains(increasing) {
+            return true;
+        }
+    }
+    false
+}
+
+fn has_multiple_pairs(password: &str) -> bool {
+    let mut chars = password.chars();
+    let mut already_one_pair = false;
+    let mut previous_char = chars.next().expect("at least one char.");
+    while let Some(char) = chars.next() {
+        if char == previous_char {
+            if already_one_pair {
+                return true;
+            }
+            already_one_pair = true;
+            previous_char = chars.next().unwrap_or_default();
+        } else {
+            previous_char = char;
+        }
+    }
+
+    false
+}
+
+fn get_next_password(password: String) -> String {
+    let mut has_carry = true;
+    let reversed_password_chars = password.chars().rev();
+    let mut reversed_new_password_chars: Vec<char> = Vec::new();
+    for char in reversed_password_chars {
+        let new_char = match has_carry {
+            true => *NEXT_LETTER.get(&char).expect("unknown letter"),
+            false => char,
+        };
+        reversed_new_password_chars.push(new_char);
+
+        has_carry = char == 'z' && has_carry;
+    }
+
+    reversed_new_password_chars.reverse();
+    reversed_new_password_chars.iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_password_simple() {
+        assert_eq!(
+            get_next_password(String::from("aaabcddd")),
+            String::from("aaabcdde")
+        );
+    }
+
+    #[test]
+    fn test_next_password_carry() {
+        assert_eq!(
+            get_next_password(String::from("hzzzzzzz")),
+            String::from("jaaaaaaa")
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_core::{parse, ParseError, Solution};
+use serde_json::Value;
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Value;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        serde_json::from_str(input).map_err(|error| {
+            match parse::lines(input).nth(error.line().saturating_sub(1)) {
+                Some(line) => {
+                    let index = (error.column().saturating_sub(1)).min(line.text.len());
+                    line.error_at_char(index, "valid JSON")
+                }
+                None => ParseError::at_end(input, "valid JSON"),
+            }
+        })
+    }
+
+    fn part1(json: &Self::Input) -> impl Display {
+        count_json(json, false)
+    }
+
+    fn part2(json: &Self::Input) -> impl Display {
+        count_json(json, true)
+    }
+}
+
+fn count_json(json: &Value, ignore_red: bool) -> i64 {
+    match json {
+        Value::Null => 0,
+        Value::Bool(_) => 0,
+        Value::Number(number) => number.as_i64().unwrap(),
+        Value::String(_) => 0,
+        Value::Array(json_vec) => json_vec
+            .iter()
+            .map(|json| count_json(json, ignore_red))
+            .sum(),
+        Value::Object(json_map) => {
+            if ignore_red
+                && json_map
+                    .values()
+                    .any(|value| value == &Value::String("red".to_string()))
+            {
+                return 0;
+            };
+            json_map
+                .values()
+                .map(|json| count_json(json, ignore_red))
+                .sum()
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_core::{combinatorics, parse, MaybeAnswer, ParseError, Pattern, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+type Relations = HashMap<(String, String), i32>;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Relations, HashSet<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((relations, names): &Self::Input) -> impl Display {
+        MaybeAnswer(get_max_love_count(relations, names))
+    }
+
+    fn part2((relations, names): &Self::Input) -> impl Display {
+        let mut relations = relations.clone();
+        let mut names = names.clone();
+        let me = String::from("me");
+
+        for name in names.iter() {
+            relations.insert((me.clone(), name.clone()), 0);
+            relations.insert((name.clone(), me.clone()), 0);
+        }
+        names.insert(me);
+
+        MaybeAnswer(get_max_love_count(&relations, &names))
+    }
+}
+
+/// Guests only sit next to those whose happiness change is known.
+fn get_max_love_count(relations: &Relations, names: &HashSet<String>) -> Option<i32> {
+    combinatorics::distinct_permutations(names.iter().cloned())
+        .filter_map(|permutation| get_love_count_from_permutation(permutation, relations))
+        .max()
+}
+
+fn parse_input(input: &str) -> Result<(Relations, HashSet<String>), ParseError> {
+    let mut relations = HashMap::new();
+    let mut names = HashSet::new();
+    let pattern = Pattern::new(
+        "{name} would {gain or lose} {i32} happiness units by sitting next to {name}.",
+    )
+    .unwrap();
+
+    for line in parse::lines(input) {
+        let (name_1, gain_or_lose, amount, name_2): (String, &str, i32, String) =
+            line.scan(&pattern)?;
+        let is_positive = match gain_or_lose {
+            "gain" => true,
+            "lose" => false,
+            gain_or_lose => return Err(line.error_at(gain_or_lose, "`gain` or `lose`")),
+        };
+        let amount = match is_positive {
+            true => amount,
+            false => -amount,
+        };
+
+        names.insert(name_1.clone());
+        names.insert(name_2.clone());
+        relations.insert((name_1, name_2), amount);
+    }
+
+    Ok((relations, names))
+}
+
+fn get_love_count_from_permutation(permutation: Vec<String>, relations: &Relations) -> Option<i32> {
+    let mut love_count = 0;
+    for (name_1, name_2) in permutation.into_iter().circular_tuple_windows() {
+        love_count += relations.get(&(name_1.clone(), name_2.clone()))?;
+        love_count += relations.get(&(name_2, name_1))?;
+    }
+    Some(love_count)
+}
@@ -0,0 +1,100 @@
+use aoc_core::{parse, ParseError, Pattern, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+const DURATION: u32 = 2503;
+
+pub struct Reindeer {
+    name: String,
+    fly_speed: u32,
+    fly_duration: u32,
+    rest_duration: u32,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reindeers(input)
+    }
+
+    fn part1(reindeers: &Self::Input) -> impl Display {
+        reindeers
+            .iter()
+            .map(|reindeer| get_reindeer_distance(reindeer, DURATION))
+            .max()
+            .unwrap()
+    }
+
+    fn part2(reindeers: &Self::Input) -> impl Display {
+        let mut reindeers_score = HashMap::new();
+        for reindeer in reindeers.iter() {
+            reindeers_score.insert(reindeer.name.clone(), 0);
+        }
+
+        for duration in 1..DURATION + 1 {
+            let best_reindeers = get_best_reindeers(reindeers, duration);
+            for reindeer_name in best_reindeers {
+                reindeers_score
+                    .entry(reindeer_name)
+                    .and_modify(|score| *score += 1);
+            }
+        }
+        *reindeers_score.values().max().unwrap()
+    }
+}
+
+fn parse_reindeers(input: &str) -> Result<Vec<Reindeer>, ParseError> {
+    let pattern = Pattern::new(
+        "{name} can fly {u32} km/s for {u32} seconds, but then must rest for {u32} seconds.",
+    )
+    .unwrap();
+    parse::lines(input)
+        .map(|line| parse_reindeer(line, &pattern))
+        .collect()
+}
+
+fn parse_reindeer(
+    line: parse::Line,
+    pattern: &Pattern<(String, u32, u32, u32)>,
+) -> Result<Reindeer, ParseError> {
+    let (name, fly_speed, fly_duration, rest_duration) = line.scan(pattern)?;
+
+    Ok(Reindeer {
+        name,
+        fly_speed,
+        fly_duration,
+        rest_duration,
+    })
+}
+
+fn get_best_reindeers(reindeers: &[Reindeer], duration: u32) -> HashSet<String> {
+    let mut best_reindeers = HashSet::new();
+    let mut best_distance = 0;
+
+    for reindeer in reindeers.iter() {
+        let reindeer_distance = get_reindeer_distance(reindeer, duration);
+
+        if reindeer_distance == best_distance {
+            best_reindeers.insert(reindeer.name.clone());
+        }
+        if reindeer_distance > best_distance {
+            best_reindeers = HashSet::from([reindeer.name.clone()]);
+            best_distance = reindeer_distance;
+        }
+    }
+
+    best_reindeers
+}
+
+fn get_reindeer_distance(reindeer: &Reindeer, duration: u32) -> u32 {
+    let cycle_duration = reindeer.fly_duration + reindeer.rest_duration;
+    let cycle_count = duration / cycle_duration;
+    let complete_cycle_distance = cycle_count * reindeer.fly_speed * reindeer.fly_duration;
+    let remain_distance =
+        reindeer.fly_speed * u32::min(duration % cycle_duration, reindeer.fly_duration);
+
+    complete_cycle_distance + remain_distance
+}
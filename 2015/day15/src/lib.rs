@@ -0,0 +1,117 @@
+use aoc_core::{combinatorics, parse, ParseError, Pattern, Solution};
+use std::fmt::Display;
+use std::ops::{AddAssign, Mul};
+
+const TEASPOON_COUNT: u32 = 100;
+const CALORIES: i32 = 500;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Ingredient {
+    capacity: i32,
+    durability: i32,
+    flavor: i32,
+    texture: i32,
+    calories: i32,
+}
+
+impl AddAssign for Ingredient {
+    fn add_assign(&mut self, rhs: Self) {
+        self.capacity += rhs.capacity;
+        self.durability += rhs.durability;
+        self.flavor += rhs.flavor;
+        self.texture += rhs.texture;
+        self.calories += rhs.calories;
+    }
+}
+
+impl Mul<u32> for Ingredient {
+    type Output = Self;
+
+    fn mul(self, rhs: u32) -> Ingredient {
+        Ingredient {
+            capacity: self.capacity * rhs as i32,
+            durability: self.durability * rhs as i32,
+            flavor: self.flavor * rhs as i32,
+            texture: self.texture * rhs as i32,
+            calories: self.calories * rhs as i32,
+        }
+    }
+}
+
+impl Ingredient {
+    fn get_score(&self) -> u32 {
+        (i32::max(0, self.capacity)
+            * i32::max(0, self.durability)
+            * i32::max(0, self.flavor)
+            * i32::max(0, self.texture)) as u32
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Ingredient>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ingredients(input)
+    }
+
+    fn part1(ingredients: &Self::Input) -> impl Display {
+        get_max_combination_score(ingredients, None)
+    }
+
+    fn part2(ingredients: &Self::Input) -> impl Display {
+        get_max_combination_score(ingredients, Some(CALORIES))
+    }
+}
+
+fn get_max_combination_score(ingredients: &[Ingredient], calories: Option<i32>) -> u32 {
+    let mut max_combination_score = 0;
+    for combination in combinatorics::compositions(TEASPOON_COUNT, ingredients.len()) {
+        max_combination_score = u32::max(
+            max_combination_score,
+            get_combination_score(combination, ingredients, calories),
+        );
+    }
+    max_combination_score
+}
+
+fn parse_ingredients(input: &str) -> Result<Vec<Ingredient>, ParseError> {
+    let mut ingredients = vec![];
+    let pattern = Pattern::new(
+        "{name}: capacity {i32}, durability {i32}, flavor {i32}, texture {i32}, calories {i32}",
+    )
+    .unwrap();
+
+    for line in parse::lines(input) {
+        let (_, capacity, durability, flavor, texture, calories): (&str, _, _, _, _, _) =
+            line.scan(&pattern)?;
+
+        ingredients.push(Ingredient {
+            capacity,
+            durability,
+            flavor,
+            texture,
+            calories,
+        })
+    }
+
+    Ok(ingredients)
+}
+
+fn get_combination_score(
+    combination: Vec<u32>,
+    ingredients: &[Ingredient],
+    calories: Option<i32>,
+) -> u32 {
+    let mut total_ingredients = Ingredient::default();
+    for (&spoon_count, ingredient) in combination.iter().zip(ingredients) {
+        total_ingredients += *ingredient * spoon_count;
+    }
+
+    if calories.is_some_and(|calories| total_ingredients.calories != calories) {
+        return 0;
+    }
+
+    total_ingredients.get_score()
+}
@@ -0,0 +1,93 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Pattern, Solution};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+lazy_static! {
+    static ref TICKER_TAPE: HashMap<String, u32> = HashMap::from([
+        ("children".to_string(), 3),
+        ("cats".to_string(), 7),
+        ("samoyeds".to_string(), 2),
+        ("pomeranians".to_string(), 3),
+        ("akitas".to_string(), 0),
+        ("vizslas".to_string(), 0),
+        ("goldfish".to_string(), 5),
+        ("trees".to_string(), 3),
+        ("cars".to_string(), 2),
+        ("perfumes".to_string(), 1),
+    ]);
+}
+
+pub struct Aunt {
+    number: u32,
+    compounds: Vec<(String, u32)>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Aunt>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let pattern =
+            Pattern::new("Sue {u32}: {compound}: {u32}, {compound}: {u32}, {compound}: {u32}")
+                .unwrap();
+        parse::lines(input)
+            .map(|line| parse_aunt(line, &pattern))
+            .collect()
+    }
+
+    fn part1(aunts: &Self::Input) -> impl Display {
+        MaybeAnswer(find_valid_aunt_number(aunts, check_exact_criteria))
+    }
+
+    fn part2(aunts: &Self::Input) -> impl Display {
+        MaybeAnswer(find_valid_aunt_number(aunts, check_criteria))
+    }
+}
+
+/// Number of an aunt and the quantities of three of its compounds, as scanned.
+type AuntFields<'a> = (u32, &'a str, u32, &'a str, u32, &'a str, u32);
+
+fn parse_aunt<'a>(
+    line: parse::Line<'a>,
+    pattern: &Pattern<AuntFields<'a>>,
+) -> Result<Aunt, ParseError> {
+    let (number, name_1, value_1, name_2, value_2, name_3, value_3) = line.scan(pattern)?;
+    let compounds = [(name_1, value_1), (name_2, value_2), (name_3, value_3)]
+        .into_iter()
+        .map(|(name, value)| {
+            if !TICKER_TAPE.contains_key(name) {
+                return Err(line.error_at(name, "a compound of the ticker tape"));
+            }
+            Ok((String::from(name), value))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(Aunt { number, compounds })
+}
+
+fn find_valid_aunt_number(aunts: &[Aunt], check: fn(&str, u32) -> bool) -> Option<u32> {
+    aunts
+        .iter()
+        .find(|aunt| {
+            aunt.compounds
+                .iter()
+                .all(|(name, value)| check(name, *value))
+        })
+        .map(|aunt| aunt.number)
+}
+
+fn check_exact_criteria(name: &str, value: u32) -> bool {
+    TICKER_TAPE[name] == value
+}
+
+fn check_criteria(name: &str, value: u32) -> bool {
+    match name {
+        "cats" => TICKER_TAPE[name] < value,
+        "trees" => TICKER_TAPE[name] < value,
+        "pomeranians" => TICKER_TAPE[name] > value,
+        "goldfish" => TICKER_TAPE[name] > value,
+        _ => TICKER_TAPE[name] == value,
+    }
+}
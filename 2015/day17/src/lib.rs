@@ -0,0 +1,50 @@
+use aoc_core::{combinatorics, parse, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+const EXAMPLE_TARGET_SIZE: usize = 25;
+const TARGET_SIZE: usize = 150;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (usize, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let containers_size = parse::lines(input)
+            .map(|line| line.parse::<usize>(line.text, "a container size"))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        // The example only has 5 containers to store 25 liters.
+        let target_size = if containers_size.len() <= 5 {
+            EXAMPLE_TARGET_SIZE
+        } else {
+            TARGET_SIZE
+        };
+
+        Ok((target_size, containers_size))
+    }
+
+    fn part1((target_size, containers_size): &Self::Input) -> impl Display {
+        combinatorics::count_subsets_with_sum(containers_size, *target_size)
+    }
+
+    fn part2((target_size, containers_size): &Self::Input) -> impl Display {
+        MaybeAnswer(get_minimum_combination_count(*target_size, containers_size))
+    }
+}
+
+fn get_minimum_combination_count(target_size: usize, containers_size: &[usize]) -> Option<usize> {
+    let combination_lengths: Vec<usize> =
+        combinatorics::subsets_with_sum(containers_size, target_size)
+            .map(|combination| combination.len())
+            .collect();
+
+    let minimum_container_count = *combination_lengths.iter().min()?;
+
+    Some(
+        combination_lengths
+            .into_iter()
+            .filter(|&length| length == minimum_container_count)
+            .count(),
+    )
+}
@@ -0,0 +1,94 @@
+use aoc_core::automaton::{self, Automaton, Cell, LifeRule, Rule, Topology};
+use aoc_core::render::{Animate, Animation, Frame, Rgb};
+use aoc_core::{Grid, ParseError, Point2, Solution};
+use std::fmt::Display;
+use std::time::Duration;
+
+const STEP_COUNT: usize = 100;
+const EXAMPLE_SIZE: usize = 6;
+const EXAMPLE_STEP_COUNT: usize = 4;
+const EXAMPLE_STUCK_CORNERS_STEP_COUNT: usize = 5;
+const PIXELS_BY_LIGHT: usize = 4;
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Whether each light is on.
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, "`#` or `.`", |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if grid.width() == 0 {
+            return Err(ParseError::at_end(input, "a grid of lights"));
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        let mut automaton = Automaton::new(grid.clone(), Topology::Finite);
+        automaton.run(&LifeRule::CONWAY, get_step_count(grid, EXAMPLE_STEP_COUNT));
+        automaton.living_cells().count()
+    }
+
+    fn part2(grid: &Self::Input) -> impl Display {
+        let step_count = get_step_count(grid, EXAMPLE_STUCK_CORNERS_STEP_COUNT);
+        let (width, height) = (grid.width() as isize, grid.height() as isize);
+        let is_corner = |position: Point2<isize>| {
+            (position.x == 0 || position.x == width - 1)
+                && (position.y == 0 || position.y == height - 1)
+        };
+        let stuck_corners_rule = automaton::rule_fn(1, |light: &Cell<'_, bool>| {
+            is_corner(light.position()) || LifeRule::CONWAY.next_state(light)
+        });
+
+        let mut grid = grid.clone();
+        add_corners(&mut grid);
+        let mut automaton = Automaton::new(grid, Topology::Finite);
+        automaton.run(&stuck_corners_rule, step_count);
+        automaton.living_cells().count()
+    }
+}
+
+impl Animate for Day {
+    /// Lights of the first part, step by step.
+    fn animate(grid: &Self::Input) -> Animation {
+        let mut animation = Animation::new(Duration::from_millis(100));
+        let mut automaton = Automaton::new(grid.clone(), Topology::Finite);
+        animation.push(draw_lights(automaton.cells()));
+
+        for _ in 0..get_step_count(grid, EXAMPLE_STEP_COUNT) {
+            automaton.step(&LifeRule::CONWAY);
+            animation.push(draw_lights(automaton.cells()));
+        }
+
+        animation
+    }
+}
+
+fn get_step_count(grid: &Grid<bool>, example_step_count: usize) -> usize {
+    if grid.width() == EXAMPLE_SIZE {
+        example_step_count
+    } else {
+        STEP_COUNT
+    }
+}
+
+fn draw_lights(grid: &Grid<bool>) -> Frame {
+    Frame::from_grid(
+        grid,
+        |&is_on| if is_on { Rgb::YELLOW } else { Rgb::DARK_GREY },
+    )
+    .scaled(PIXELS_BY_LIGHT)
+}
+
+fn add_corners(grid: &mut Grid<bool>) {
+    let (last_x, last_y) = (grid.width() - 1, grid.height() - 1);
+    for corner in [(0, 0), (0, last_y), (last_x, 0), (last_x, last_y)] {
+        grid[corner] = true;
+    }
+}
@@ -1,182 +1,5 @@
-use std::{
-    collections::HashSet,
-    env,
-    fmt::{self, Display},
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::process::ExitCode;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
-struct Position {
-    x: usize,
-    y: usize,
-}
-
-#[derive(Debug)]
-struct Grid {
-    positions_on: HashSet<Position>,
-    size: usize,
-}
-
-impl Display for Grid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for x in 0..self.size {
-            let line = (0..self.size)
-                .map(|y| {
-                    if self.positions_on.contains(&Position { x, y }) {
-                        '#'
-                    } else {
-                        '.'
-                    }
-                })
-                .collect::<String>();
-            if let Err(error) = write!(f, "{}\n", line) {
-                return Err(error);
-            };
-        }
-
-        Ok(())
-    }
-}
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let file_path = args.get(1).expect("to be given an input file.");
-
-    let mut grid = parse_grid(&file_path);
-
-    for _ in 0..100 {
-        grid = next_step(grid);
-    }
-    println!("{}", grid);
-    println!("{}", grid.positions_on.len());
-}
-
-fn parse_grid(file_path: &String) -> Grid {
-    let mut grid = HashSet::new();
-    let mut last_x = 0;
-
-    let file = File::open(file_path).expect("File not found!");
-    let mut line_iter = BufReader::new(file).lines().enumerate();
-
-    while let Some((x, Ok(line))) = line_iter.next() {
-        for (y, char) in line.chars().enumerate() {
-            if char == '#' {
-                grid.insert(Position { x, y });
-            }
-        }
-        last_x = x;
-    }
-
-    let mut grid = Grid {
-        positions_on: grid,
-        size: last_x + 1,
-    };
-
-    add_corners(&mut grid);
-    grid
-}
-
-fn next_step(grid: Grid) -> Grid {
-    let mut next_positions_on = HashSet::new();
-
-    for x in 0..grid.size {
-        for y in 0..grid.size {
-            let current_position = Position { x, y };
-
-            if is_light_on_next_grid(&current_position, &grid) {
-                next_positions_on.insert(current_position);
-            }
-        }
-    }
-
-    let mut next_grid = Grid {
-        positions_on: next_positions_on,
-        size: grid.size,
-    };
-    add_corners(&mut next_grid);
-    next_grid
-}
-
-fn is_light_on_next_grid(position: &Position, grid: &Grid) -> bool {
-    let neighbors = get_neighbors(&position);
-
-    let turn_on_count = neighbors
-        .into_iter()
-        .filter(|neighbor| grid.positions_on.contains(neighbor))
-        .count();
-
-    if grid.positions_on.contains(position) {
-        turn_on_count == 2 || turn_on_count == 3
-    } else {
-        turn_on_count == 3
-    }
-}
-
-fn get_neighbors(position: &Position) -> HashSet<Position> {
-    let mut neighbors = HashSet::from([
-        Position {
-            x: position.x + 1,
-            y: position.y,
-        },
-        Position {
-            x: position.x,
-            y: position.y + 1,
-        },
-        Position {
-            x: position.x + 1,
-            y: position.y + 1,
-        },
-    ]);
-
-    if position.x > 0 && position.y > 0 {
-        neighbors.insert(Position {
-            x: position.x - 1,
-            y: position.y - 1,
-        });
-    }
-
-    if position.x > 0 {
-        neighbors.extend([
-            Position {
-                x: position.x - 1,
-                y: position.y,
-            },
-            Position {
-                x: position.x - 1,
-                y: position.y + 1,
-            },
-        ]);
-    }
-
-    if position.y > 0 {
-        neighbors.extend([
-            Position {
-                x: position.x,
-                y: position.y - 1,
-            },
-            Position {
-                x: position.x + 1,
-                y: position.y - 1,
-            },
-        ]);
-    }
-
-    neighbors
-}
-
-fn add_corners(grid: &mut Grid) {
-    grid.positions_on.insert(Position { x: 0, y: 0 });
-    grid.positions_on.insert(Position {
-        x: 0,
-        y: grid.size - 1,
-    });
-    grid.positions_on.insert(Position {
-        x: grid.size - 1,
-        y: 0,
-    });
-    grid.positions_on.insert(Position {
-        x: grid.size - 1,
-        y: grid.size - 1,
-    });
+fn main() -> ExitCode {
+    aoc_core::run::<day18_2015::Day>(&aoc_core::input!())
 }
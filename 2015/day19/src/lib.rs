@@ -0,0 +1,109 @@
+use aoc_core::{parse, ParseError, Solution};
+use log::trace;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashMap<String, String>, String);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((reverse_transitions, molecule): &Self::Input) -> impl Display {
+        let mut calibration_molecules = HashSet::new();
+
+        for (result, target) in reverse_transitions {
+            for (start_index, _) in molecule.match_indices(target.as_str()) {
+                let mut new_molecule = molecule.clone();
+                new_molecule.replace_range(start_index..(start_index + target.len()), result);
+                calibration_molecules.insert(new_molecule);
+            }
+        }
+
+        calibration_molecules.len()
+    }
+
+    fn part2((reverse_transitions, target_molecule): &Self::Input) -> impl Display {
+        get_shortest_count(target_molecule.clone(), reverse_transitions)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(HashMap<String, String>, String), ParseError> {
+    let mut reverse_transitions = HashMap::new();
+
+    let mut line_iter = parse::lines(input);
+
+    for line in line_iter.by_ref() {
+        if line.text.is_empty() {
+            break;
+        }
+
+        let Some((start, result)) = line.text.split_once(" => ") else {
+            return Err(line.error("a replacement like `H => HO`"));
+        };
+        reverse_transitions.insert(result.to_string(), start.to_string());
+    }
+
+    let target_molecule = line_iter
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "the medicine molecule"))?
+        .text
+        .to_string();
+
+    Ok((reverse_transitions, target_molecule))
+}
+
+fn get_next_molecules(
+    molecules: HashSet<String>,
+    reverse_transitions: &HashMap<String, String>,
+) -> HashSet<String> {
+    let mut new_molecules = HashSet::new();
+
+    for molecule in molecules {
+        for (target, result) in reverse_transitions {
+            for (start_index, _) in molecule.match_indices(target) {
+                let mut new_molecule = molecule.clone();
+                new_molecule.replace_range(start_index..(start_index + target.len()), result);
+                new_molecules.insert(new_molecule);
+            }
+        }
+    }
+    new_molecules
+}
+
+fn get_shortest_count(
+    target_molecule: String,
+    reverse_transitions: &HashMap<String, String>,
+) -> u32 {
+    let mut count = 0;
+    let mut molecules = HashSet::from([target_molecule]);
+
+    while !molecules.contains("e") {
+        molecules = get_next_molecules(molecules, reverse_transitions);
+        molecules = get_reduced_molecules(molecules);
+        count += 1;
+        trace!("Step {count}: {molecules:?}");
+    }
+
+    count
+}
+
+fn get_reduced_molecules(mut molecules: HashSet<String>) -> HashSet<String> {
+    let mut reduced_molecules = HashSet::new();
+
+    for _ in 0..usize::min(20, molecules.len()) {
+        let molecule = molecules
+            .iter()
+            .min_by_key(|molecule| molecule.len())
+            .expect("minimum length molecule.")
+            .clone();
+
+        molecules.remove(&molecule);
+        reduced_molecules.insert(molecule);
+    }
+
+    reduced_molecules
+}
@@ -0,0 +1,41 @@
+use aoc_core::{number_theory, parse, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+/// Elves visiting at most that many houses in the second part.
+const MAX_VISITED_HOUSES: usize = 50;
+
+impl Solution for Day {
+    type Input = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "a number of presents"))?;
+        line.parse::<u64>(line.text.trim_end(), "a number of presents")
+    }
+
+    fn part1(&target_score: &Self::Input) -> impl Display {
+        // The elf with the number of the house delivers enough presents on its own there.
+        let max_house_number = target_score.div_ceil(10).max(1) as usize;
+        let divisor_sums = number_theory::sigma_sieve(max_house_number);
+
+        get_first_house(&divisor_sums, 10, target_score)
+    }
+
+    fn part2(&target_score: &Self::Input) -> impl Display {
+        let max_house_number = target_score.div_ceil(11).max(1) as usize;
+        let divisor_sums = number_theory::bounded_sigma_sieve(max_house_number, MAX_VISITED_HOUSES);
+
+        get_first_house(&divisor_sums, 11, target_score)
+    }
+}
+
+/// First house getting at least `target_score` presents, each elf delivering
+/// `presents_by_elf` times its number.
+fn get_first_house(divisor_sums: &[u64], presents_by_elf: u64, target_score: u64) -> usize {
+    (1..divisor_sums.len())
+        .find(|&house_number| presents_by_elf * divisor_sums[house_number] >= target_score)
+        .unwrap()
+}
@@ -0,0 +1,228 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::fmt::Display;
+use std::iter::Sum;
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    cost: u32,
+    damage: u32,
+    armor: u32,
+}
+
+impl Sum for Item {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, item| Item {
+            cost: acc.cost + item.cost,
+            damage: acc.damage + item.damage,
+            armor: acc.armor + item.armor,
+        })
+        .unwrap()
+    }
+}
+
+const WEAPONS: [Item; 5] = [
+    Item {
+        cost: 8,
+        damage: 4,
+        armor: 0,
+    },
+    Item {
+        cost: 10,
+        damage: 5,
+        armor: 0,
+    },
+    Item {
+        cost: 25,
+        damage: 6,
+        armor: 0,
+    },
+    Item {
+        cost: 40,
+        damage: 7,
+        armor: 0,
+    },
+    Item {
+        cost: 74,
+        damage: 8,
+        armor: 0,
+    },
+];
+
+const ARMORS: [Item; 5] = [
+    Item {
+        cost: 13,
+        damage: 0,
+        armor: 1,
+    },
+    Item {
+        cost: 31,
+        damage: 0,
+        armor: 2,
+    },
+    Item {
+        cost: 53,
+        damage: 0,
+        armor: 3,
+    },
+    Item {
+        cost: 75,
+        damage: 0,
+        armor: 4,
+    },
+    Item {
+        cost: 102,
+        damage: 0,
+        armor: 5,
+    },
+];
+
+const RINGS: [Item; 6] = [
+    Item {
+        cost: 25,
+        damage: 1,
+        armor: 0,
+    },
+    Item {
+        cost: 50,
+        damage: 2,
+        armor: 0,
+    },
+    Item {
+        cost: 100,
+        damage: 3,
+        armor: 0,
+    },
+    Item {
+        cost: 20,
+        damage: 0,
+        armor: 1,
+    },
+    Item {
+        cost: 40,
+        damage: 0,
+        armor: 2,
+    },
+    Item {
+        cost: 80,
+        damage: 0,
+        armor: 3,
+    },
+];
+
+#[derive(Debug)]
+pub struct Enemy {
+    hp: u32,
+    damage: u32,
+    armor: u32,
+}
+
+const PLAYER_HP: u32 = 100;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Enemy;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_enemy(input)
+    }
+
+    fn part1(enemy: &Self::Input) -> impl Display {
+        let mut min_cost = u32::MAX;
+
+        for set in generate_sets() {
+            if is_winning_set(&set, enemy) {
+                min_cost = u32::min(min_cost, set.cost);
+            }
+        }
+
+        min_cost
+    }
+
+    fn part2(enemy: &Self::Input) -> impl Display {
+        let mut max_cost = 0;
+
+        for set in generate_sets() {
+            if !is_winning_set(&set, enemy) {
+                max_cost = u32::max(max_cost, set.cost);
+            }
+        }
+
+        max_cost
+    }
+}
+
+fn parse_enemy(input: &str) -> Result<Enemy, ParseError> {
+    let mut lines = parse::lines(input);
+    let mut next_stat = |name: &str| {
+        let expected = format!("`{name}: <value>`");
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, &expected))?;
+
+        match line.text.split_once(": ") {
+            Some((stat_name, value)) if stat_name == name => line.parse::<u32>(value, "a number"),
+            _ => Err(line.error(expected)),
+        }
+    };
+
+    Ok(Enemy {
+        hp: next_stat("Hit Points")?,
+        damage: next_stat("Damage")?,
+        armor: next_stat("Armor")?,
+    })
+}
+
+fn generate_sets() -> Vec<Item> {
+    let mut sets = vec![];
+
+    for weapon in WEAPONS {
+        for armor_index in -1..ARMORS.len() as i32 {
+            for ring_1_index in -2..RINGS.len() as i32 {
+                for ring_2_index in (ring_1_index + 1)..RINGS.len() as i32 {
+                    let mut items = vec![weapon];
+
+                    if armor_index >= 0 {
+                        items.push(ARMORS[armor_index as usize]);
+                    }
+
+                    if ring_1_index >= 0 {
+                        items.push(RINGS[ring_1_index as usize]);
+                    }
+
+                    if ring_2_index >= 0 {
+                        items.push(RINGS[ring_2_index as usize]);
+                    }
+
+                    sets.push(items.into_iter().sum::<Item>());
+                }
+            }
+        }
+    }
+    sets
+}
+
+fn is_winning_set(set: &Item, enemy: &Enemy) -> bool {
+    let turn_to_kill_player = get_turn_count_to_kill(enemy.damage, PLAYER_HP, set.armor);
+    let turn_to_kill_enemy = get_turn_count_to_kill(set.damage, enemy.hp, enemy.armor);
+
+    turn_to_kill_player >= turn_to_kill_enemy
+}
+
+fn get_turn_count_to_kill(attacker_damage: u32, defender_hp: u32, defender_armor: u32) -> u32 {
+    let turn_damage = get_damage(attacker_damage, defender_armor);
+    defender_hp / turn_damage
+        + if !defender_hp.is_multiple_of(turn_damage) {
+            1
+        } else {
+            0
+        }
+}
+
+fn get_damage(attacker_damage: u32, defender_armor: u32) -> u32 {
+    if defender_armor >= attacker_damage {
+        1
+    } else {
+        attacker_damage - defender_armor
+    }
+}
@@ -0,0 +1,382 @@
+use aoc_core::branch_and_bound::{self, Problem};
+use aoc_core::{parse, ParseError, Solution};
+use log::trace;
+use std::cmp::Reverse;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Spell {
+    InstantSpell(Instant),
+    EffectSpell(Effect),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Instant {
+    MagicMissile,
+    Drain,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Effect {
+    Shield,
+    Poison,
+    Recharge,
+}
+
+#[derive(Debug, PartialEq)]
+enum EndGame {
+    PlayerWin,
+    MonsterWin,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct EffectsCount {
+    shield_count: u8,
+    poison_count: u8,
+    recharge_count: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Game {
+    player_mana: u32,
+    player_hp: u32,
+
+    monster_hp: u32,
+    monster_damage: u32,
+
+    effects: EffectsCount,
+    mana_spent: u32,
+
+    hard_mode: bool,
+}
+
+/// Spells cast by the player until the monster dies, a won game having a monster without hit
+/// points left.
+struct Fight;
+
+const MAGIC_MISSILE_COST: u32 = 53;
+const MAGIC_MISSILE_DAMAGE: u32 = 4;
+
+const DRAIN_COST: u32 = 73;
+const DRAIN_TRANSFER: u32 = 2;
+
+const SHIELD_COST: u32 = 113;
+const SHIELD_DURATION: u8 = 6;
+const SHIELD_ARMOR_GAIN: u32 = 7;
+
+const POISON_COST: u32 = 173;
+const POISON_DURATION: u8 = 6;
+const POISON_DAMAGE: u32 = 3;
+
+const RECHARGE_COST: u32 = 229;
+const RECHARGE_DURATION: u8 = 5;
+const RECHARGE_MANA_GAIN: u32 = 101;
+
+const PLAYER_HP: u32 = 50;
+const PLAYER_MANA: u32 = 500;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (u32, u32);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_monster(input)
+    }
+
+    fn part1(&(monster_hp, monster_damage): &Self::Input) -> impl Display {
+        get_min_mana_spent(monster_hp, monster_damage, false)
+    }
+
+    fn part2(&(monster_hp, monster_damage): &Self::Input) -> impl Display {
+        get_min_mana_spent(monster_hp, monster_damage, true)
+    }
+}
+
+fn get_min_mana_spent(monster_hp: u32, monster_damage: u32, hard_mode: bool) -> u32 {
+    let initial_game = Game {
+        player_hp: PLAYER_HP,
+        player_mana: PLAYER_MANA,
+        monster_hp,
+        monster_damage,
+        effects: EffectsCount {
+            shield_count: 0,
+            poison_count: 0,
+            recharge_count: 0,
+        },
+        mana_spent: 0,
+        hard_mode,
+    };
+
+    let Reverse(min_mana_spent) = branch_and_bound::best_first(&Fight, initial_game)
+        .unwrap()
+        .value;
+    min_mana_spent
+}
+
+fn parse_monster(input: &str) -> Result<(u32, u32), ParseError> {
+    let mut lines = parse::lines(input);
+    let mut next_stat = |name: &str| {
+        let expected = format!("`{name}: <value>`");
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, &expected))?;
+
+        match line.text.split_once(": ") {
+            Some((stat_name, value)) if stat_name == name => line.parse::<u32>(value, "a number"),
+            _ => Err(line.error(expected)),
+        }
+    };
+
+    Ok((next_stat("Hit Points")?, next_stat("Damage")?))
+}
+
+/// Spending mana is the only way to win, so the mana already spent bounds it.
+impl Problem for Fight {
+    type State = Game;
+    type Move = Spell;
+    type Value = Reverse<u32>;
+
+    fn successors(&self, game: &Game) -> Vec<(Spell, Game)> {
+        if game.monster_hp == 0 {
+            return Vec::new();
+        }
+
+        get_castable_spells(game)
+            .into_iter()
+            .filter_map(|spell| {
+                let mut next_game = game.clone();
+                match play(&mut next_game, spell.clone()) {
+                    Ok(()) => {}
+                    Err(EndGame::PlayerWin) => next_game.monster_hp = 0,
+                    Err(EndGame::MonsterWin) => return None,
+                }
+                Some((spell, next_game))
+            })
+            .collect()
+    }
+
+    fn value(&self, game: &Game) -> Option<Reverse<u32>> {
+        (game.monster_hp == 0).then_some(Reverse(game.mana_spent))
+    }
+
+    fn bound(&self, game: &Game) -> Reverse<u32> {
+        Reverse(game.mana_spent)
+    }
+}
+
+/// Every instant spell, and the effects ending before the player turn.
+fn get_castable_spells(game: &Game) -> Vec<Spell> {
+    let instants = [Instant::Drain, Instant::MagicMissile].map(Spell::InstantSpell);
+    let effects = [Effect::Shield, Effect::Poison, Effect::Recharge]
+        .into_iter()
+        .filter(|effect| {
+            let effect_count = match effect {
+                Effect::Shield => game.effects.shield_count,
+                Effect::Poison => game.effects.poison_count,
+                Effect::Recharge => game.effects.recharge_count,
+            };
+            effect_count <= 1
+        })
+        .map(Spell::EffectSpell);
+
+    instants.into_iter().chain(effects).collect()
+}
+
+fn tick_spells(effects: &mut EffectsCount) {
+    effects.shield_count = effects.shield_count.saturating_sub(1);
+    effects.poison_count = effects.poison_count.saturating_sub(1);
+    effects.recharge_count = effects.recharge_count.saturating_sub(1);
+}
+
+fn play(game: &mut Game, spell: Spell) -> Result<(), EndGame> {
+    play_player_turn(game, spell)?;
+    play_monster_turn(game)?;
+
+    Ok(())
+}
+
+fn play_player_turn(game: &mut Game, spell: Spell) -> Result<(), EndGame> {
+    trace!("-- Player turn --");
+    if game.hard_mode {
+        if game.player_hp <= 1 {
+            return Err(EndGame::MonsterWin);
+        }
+        game.player_hp -= 1;
+    }
+
+    play_default_actions(game)?;
+
+    if spell == Spell::InstantSpell(Instant::MagicMissile) {
+        trace!("Player casts Magic Missile.");
+        if game.player_mana < MAGIC_MISSILE_COST {
+            return Err(EndGame::MonsterWin);
+        }
+        game.player_mana -= MAGIC_MISSILE_COST;
+        game.mana_spent += MAGIC_MISSILE_COST;
+
+        if game.monster_hp <= MAGIC_MISSILE_DAMAGE {
+            return Err(EndGame::PlayerWin);
+        }
+        game.monster_hp -= MAGIC_MISSILE_DAMAGE;
+    }
+
+    if spell == Spell::InstantSpell(Instant::Drain) {
+        trace!("Player casts Drain.");
+        if game.player_mana < DRAIN_COST {
+            return Err(EndGame::MonsterWin);
+        }
+        game.player_mana -= DRAIN_COST;
+        game.mana_spent += DRAIN_COST;
+
+        if game.monster_hp <= DRAIN_TRANSFER {
+            return Err(EndGame::PlayerWin);
+        }
+        game.monster_hp -= DRAIN_TRANSFER;
+        game.player_hp += DRAIN_TRANSFER;
+    }
+
+    if spell == Spell::EffectSpell(Effect::Recharge) {
+        trace!("Player casts Recharge.");
+        if game.player_mana < RECHARGE_COST {
+            return Err(EndGame::MonsterWin);
+        }
+        game.player_mana -= RECHARGE_COST;
+        game.mana_spent += RECHARGE_COST;
+        game.effects.recharge_count = RECHARGE_DURATION;
+    }
+
+    if spell == Spell::EffectSpell(Effect::Shield) {
+        trace!("Player casts Shield.");
+        if game.player_mana < SHIELD_COST {
+            return Err(EndGame::MonsterWin);
+        }
+        game.player_mana -= SHIELD_COST;
+        game.mana_spent += SHIELD_COST;
+        game.effects.shield_count = SHIELD_DURATION;
+    }
+
+    if spell == Spell::EffectSpell(Effect::Poison) {
+        trace!("Player casts Poison.");
+        if game.player_mana < POISON_COST {
+            return Err(EndGame::MonsterWin);
+        }
+        game.player_mana -= POISON_COST;
+        game.mana_spent += POISON_COST;
+        game.effects.poison_count = POISON_DURATION;
+    }
+
+    Ok(())
+}
+
+fn play_monster_turn(game: &mut Game) -> Result<(), EndGame> {
+    trace!("-- Boss turn --");
+    play_default_actions(game)?;
+
+    let monster_damage = if game.effects.shield_count > 0 {
+        trace!("Shield's timer is now {}.", game.effects.shield_count - 1);
+        get_damage(game.monster_damage, SHIELD_ARMOR_GAIN)
+    } else {
+        get_damage(game.monster_damage, 0)
+    };
+
+    trace!("Boss attacks for {monster_damage} damage!");
+
+    if game.player_hp <= monster_damage {
+        return Err(EndGame::MonsterWin);
+    }
+    game.player_hp -= monster_damage;
+
+    Ok(())
+}
+
+fn play_default_actions(game: &mut Game) -> Result<(), EndGame> {
+    trace!(
+        "- Player has {} hit points, {} mana",
+        game.player_hp,
+        game.player_mana
+    );
+    trace!("- Boss has {} hit points", game.monster_hp);
+
+    if game.effects.shield_count > 0 {
+        trace!("Shield's timer is now {}.", game.effects.shield_count - 1);
+    }
+
+    if game.effects.poison_count > 0 {
+        trace!(
+            "Poison deals {} damage; its timer is now {}.",
+            POISON_DAMAGE,
+            game.effects.poison_count - 1
+        );
+        if game.monster_hp <= POISON_DAMAGE {
+            return Err(EndGame::PlayerWin);
+        }
+        game.monster_hp -= POISON_DAMAGE;
+    }
+
+    if game.effects.recharge_count > 0 {
+        trace!(
+            "Recharge provides {} mana; its timer is now {}.",
+            RECHARGE_MANA_GAIN,
+            game.effects.recharge_count - 1
+        );
+        game.player_mana += RECHARGE_MANA_GAIN;
+    }
+
+    tick_spells(&mut game.effects);
+    Ok(())
+}
+
+fn get_damage(attacker_damage: u32, defender_armor: u32) -> u32 {
+    if defender_armor >= attacker_damage {
+        1
+    } else {
+        attacker_damage - defender_armor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_double_overlap() {
+        let mut initial_game = Game {
+            player_hp: 10,
+            player_mana: 250,
+            monster_hp: 14,
+            monster_damage: 8,
+            effects: EffectsCount {
+                shield_count: 0,
+                poison_count: 0,
+                recharge_count: 0,
+            },
+            mana_spent: 0,
+            hard_mode: false,
+        };
+
+        assert_eq!(
+            play(&mut initial_game, Spell::EffectSpell(Effect::Recharge)),
+            Ok(())
+        );
+        assert_eq!(
+            play(&mut initial_game, Spell::EffectSpell(Effect::Shield)),
+            Ok(())
+        );
+        assert_eq!(
+            play(&mut initial_game, Spell::InstantSpell(Instant::Drain)),
+            Ok(())
+        );
+        assert_eq!(
+            play(&mut initial_game, Spell::EffectSpell(Effect::Poison)),
+            Ok(())
+        );
+        assert_eq!(
+            play(
+                &mut initial_game,
+                Spell::InstantSpell(Instant::MagicMissile),
+            ),
+            Err(EndGame::PlayerWin)
+        );
+    }
+}
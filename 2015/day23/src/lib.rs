@@ -0,0 +1,133 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum Instruction {
+    Half(char),
+    Triple(char),
+    Increment(char),
+    Jump(i32),
+    JumpEven(char, i32),
+    JumpOne(char, i32),
+}
+
+#[derive(Debug)]
+struct InnerState {
+    instruction_index: i32,
+    a: u32,
+    b: u32,
+}
+
+impl InnerState {
+    fn get(&self, register: char) -> u32 {
+        match register {
+            'a' => self.a,
+            'b' => self.b,
+            _ => panic!("Unknown register: {}", register),
+        }
+    }
+
+    fn set(&mut self, register: char, value: u32) {
+        match register {
+            'a' => self.a = value,
+            'b' => self.b = value,
+            _ => panic!("Unknown register: {}", register),
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> impl Display {
+        run_program(instructions, 0)
+    }
+
+    fn part2(instructions: &Self::Input) -> impl Display {
+        run_program(instructions, 1)
+    }
+}
+
+fn run_program(instructions: &[Instruction], a: u32) -> u32 {
+    let mut inner_state = InnerState {
+        instruction_index: 0,
+        a,
+        b: 0,
+    };
+
+    while inner_state.instruction_index < instructions.len() as i32 {
+        execute_one(&mut inner_state, instructions);
+    }
+
+    inner_state.b
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input).map(parse_instruction).collect()
+}
+
+fn parse_instruction(line: parse::Line) -> Result<Instruction, ParseError> {
+    let parts = line.text.split(' ').collect::<Vec<_>>();
+    let register = |index: usize| match parts.get(index).map(|part| part.trim_end_matches(',')) {
+        Some("a") => Ok('a'),
+        Some("b") => Ok('b'),
+        Some(part) => Err(line.error_at(part, "register `a` or `b`")),
+        None => Err(line.error_at_end("register `a` or `b`")),
+    };
+    let offset = |index: usize| match parts.get(index) {
+        Some(part) => line.parse::<i32>(part, "an offset"),
+        None => Err(line.error_at_end("an offset")),
+    };
+
+    match parts[0] {
+        "hlf" => Ok(Instruction::Half(register(1)?)),
+        "tpl" => Ok(Instruction::Triple(register(1)?)),
+        "inc" => Ok(Instruction::Increment(register(1)?)),
+        "jmp" => Ok(Instruction::Jump(offset(1)?)),
+        "jie" => Ok(Instruction::JumpEven(register(1)?, offset(2)?)),
+        "jio" => Ok(Instruction::JumpOne(register(1)?, offset(2)?)),
+        name => Err(line.error_at(name, "one of `hlf`, `tpl`, `inc`, `jmp`, `jie` or `jio`")),
+    }
+}
+
+fn execute_one(inner_state: &mut InnerState, instructions: &[Instruction]) {
+    let instruction = &instructions[inner_state.instruction_index as usize];
+
+    match instruction {
+        Instruction::Half(register) => {
+            inner_state.set(*register, inner_state.get(*register) / 2);
+            inner_state.instruction_index += 1;
+        }
+        Instruction::Triple(register) => {
+            inner_state.set(*register, inner_state.get(*register) * 3);
+            inner_state.instruction_index += 1;
+        }
+        Instruction::Increment(register) => {
+            inner_state.set(*register, inner_state.get(*register) + 1);
+            inner_state.instruction_index += 1;
+        }
+        Instruction::Jump(offset) => {
+            inner_state.instruction_index += offset;
+        }
+        Instruction::JumpEven(register, offset) => {
+            if inner_state.get(*register).is_multiple_of(2) {
+                inner_state.instruction_index += offset;
+            } else {
+                inner_state.instruction_index += 1;
+            }
+        }
+        Instruction::JumpOne(register, offset) => {
+            if inner_state.get(*register) == 1 {
+                inner_state.instruction_index += offset;
+            } else {
+                inner_state.instruction_index += 1;
+            }
+        }
+    }
+}
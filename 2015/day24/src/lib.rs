@@ -0,0 +1,110 @@
+use aoc_core::{combinatorics, parse, MaybeAnswer, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_packets(input)
+    }
+
+    fn part1(packets: &Self::Input) -> impl Display {
+        MaybeAnswer(get_min_quantum_entanglement(packets, 3))
+    }
+
+    fn part2(packets: &Self::Input) -> impl Display {
+        MaybeAnswer(get_min_quantum_entanglement(packets, 4))
+    }
+}
+
+fn get_min_quantum_entanglement(packets: &[u128], group_count: u128) -> Option<u128> {
+    let total_weight: u128 = packets.iter().sum();
+    if !total_weight.is_multiple_of(group_count) {
+        return None;
+    }
+    let target_weight = total_weight / group_count;
+
+    let combinations_by_size = get_combination_by_size(target_weight, packets);
+
+    for first_package_size in 1..packets.len() {
+        if !combinations_by_size.contains_key(&first_package_size) {
+            continue;
+        }
+
+        let mut min_quantum_entanglement = u128::MAX;
+
+        for first_package_packets in combinations_by_size[&first_package_size].clone() {
+            let complement_packets: Vec<_> = packets
+                .iter()
+                .copied()
+                .filter(|packet| !first_package_packets.contains(packet))
+                .collect();
+
+            if !could_be_split_equally(&complement_packets, group_count - 1) {
+                continue;
+            };
+
+            min_quantum_entanglement = u128::min(
+                min_quantum_entanglement,
+                get_quantum_entanglement(&first_package_packets),
+            );
+        }
+
+        if min_quantum_entanglement != u128::MAX {
+            return Some(min_quantum_entanglement);
+        }
+    }
+
+    None
+}
+
+fn parse_packets(input: &str) -> Result<Vec<u128>, ParseError> {
+    parse::lines(input)
+        .map(
+            |line| match line.parse::<u128>(line.text, "a packet weight")? {
+                0 => Err(line.error("a positive packet weight")),
+                weight => Ok(weight),
+            },
+        )
+        .collect()
+}
+
+fn get_combination_by_size(
+    target_weight: u128,
+    weights_combination: &[u128],
+) -> HashMap<usize, HashSet<Vec<u128>>> {
+    let mut combinations_by_size: HashMap<usize, HashSet<Vec<u128>>> = HashMap::new();
+    for combination in combinatorics::subsets_with_sum(weights_combination, target_weight) {
+        combinations_by_size
+            .entry(combination.len())
+            .or_default()
+            .insert(combination);
+    }
+    combinations_by_size
+}
+
+fn could_be_split_equally(packets: &[u128], group_count: u128) -> bool {
+    let packet_size: u128 = packets.iter().sum();
+    let mut second_packages_packets =
+        combinatorics::subsets_with_sum(packets, packet_size / group_count);
+    if group_count <= 2 {
+        return second_packages_packets.next().is_some();
+    }
+
+    second_packages_packets.any(|second_package_packets| {
+        let complement_packets: Vec<_> = packets
+            .iter()
+            .copied()
+            .filter(|packet| !second_package_packets.contains(packet))
+            .collect();
+
+        could_be_split_equally(&complement_packets, group_count - 1)
+    })
+}
+
+fn get_quantum_entanglement(packets: &[u128]) -> u128 {
+    packets.iter().product()
+}
@@ -0,0 +1,52 @@
+use aoc_core::{number_theory, parse, ParseError, Solution};
+use std::fmt::Display;
+
+/// Each code is the previous one times `MULTIPLIER`, modulo `MODULUS`.
+const FIRST_CODE: u64 = 20151125;
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_coords(input)
+    }
+
+    fn part1(&(row, column): &Self::Input) -> impl Display {
+        let exponent = translate_coords(row, column) - 1;
+        FIRST_CODE * number_theory::modpow(MULTIPLIER, exponent, MODULUS) % MODULUS
+    }
+
+    /// The last day only has one puzzle, the second star comes with the 49 others.
+    fn part2(_: &Self::Input) -> impl Display {
+        "Merry Christmas!"
+    }
+}
+
+fn parse_coords(input: &str) -> Result<(u64, u64), ParseError> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "the code coordinates"))?;
+
+    let numbers = line
+        .text
+        .split(|char: char| !char.is_ascii_digit())
+        .filter(|part| !part.is_empty())
+        .map(|part| line.parse::<u64>(part, "a number"))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+
+    match numbers[..] {
+        [row, column] => Ok((row, column)),
+        _ => Err(line.error("a row and a column, like `row 2947, column 3029`")),
+    }
+}
+
+fn translate_coords(row: u64, column: u64) -> u64 {
+    let diagonal = row + column - 1;
+    let diagonal_fist_value = 1 + (diagonal * (diagonal - 1)) / 2;
+
+    diagonal_fist_value + column - 1
+}
@@ -0,0 +1,55 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut elves_calories = Vec::new();
+        let mut current_calories = 0;
+
+        for line in parse::lines(input) {
+            if !line.text.is_empty() {
+                current_calories += line.parse::<u32>(line.text, "a number of calories")?;
+            } else {
+                elves_calories.push(current_calories);
+                current_calories = 0;
+            }
+        }
+        elves_calories.push(current_calories);
+
+        Ok(elves_calories)
+    }
+
+    fn part1(elves_calories: &Self::Input) -> impl Display {
+        *elves_calories.iter().max().unwrap()
+    }
+
+    fn part2(elves_calories: &Self::Input) -> impl Display {
+        let mut maximums_sorted_calories: [u32; 3] = [0; 3];
+
+        for &calories in elves_calories.iter() {
+            maximums_sorted_calories = update_maximum_calories(maximums_sorted_calories, calories);
+        }
+        maximums_sorted_calories.iter().sum::<u32>()
+    }
+}
+
+fn update_maximum_calories(maximums_sorted_calories: [u32; 3], new_calories: u32) -> [u32; 3] {
+    let mut calories_to_insert = new_calories;
+    let mut new_maximums_sorted_calories = [0; 3];
+
+    for item in maximums_sorted_calories.into_iter().enumerate() {
+        let (index, calories) = item;
+        if calories_to_insert > calories {
+            new_maximums_sorted_calories[index] = calories_to_insert;
+            calories_to_insert = maximums_sorted_calories[index];
+        } else {
+            new_maximums_sorted_calories[index] = maximums_sorted_calories[index];
+        }
+    }
+
+    new_maximums_sorted_calories
+}
@@ -0,0 +1,74 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::fmt::Display;
+
+const ENEMY: [char; 3] = ['A', 'B', 'C'];
+const ALLY: [char; 3] = ['X', 'Y', 'Z'];
+// Rock, Paper, Scissors
+
+const LOOSE: char = 'X';
+const DRAW: char = 'Y';
+const WIN: char = 'Z';
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| match line.text.as_bytes() {
+                &[enemy, b' ', ally] if ENEMY.contains(&(enemy as char)) => {
+                    if ALLY.contains(&(ally as char)) {
+                        Ok((enemy as char, ally as char))
+                    } else {
+                        Err(line.error_at_char(2, "`X`, `Y` or `Z`"))
+                    }
+                }
+                _ => Err(line.error("a round like `A Y`")),
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input) -> impl Display {
+        rounds
+            .iter()
+            .map(|&(enemy, ally)| get_score(index_of(&ALLY, ally), index_of(&ENEMY, enemy)))
+            .sum::<usize>()
+    }
+
+    fn part2(rounds: &Self::Input) -> impl Display {
+        rounds
+            .iter()
+            .map(|&(enemy, goal)| get_goal_score(goal, enemy))
+            .sum::<usize>()
+    }
+}
+
+fn get_goal_score(goal: char, enemy: char) -> usize {
+    let enemy_index = index_of(&ENEMY, enemy);
+    let ally_index = match goal {
+        LOOSE => (enemy_index + 2) % 3,
+        DRAW => enemy_index,
+        WIN => (enemy_index + 1) % 3,
+        _ => 0,
+    };
+
+    get_score(ally_index, enemy_index)
+}
+
+fn get_score(ally_index: usize, enemy_index: usize) -> usize {
+    let shape_score = ally_index + 1;
+    let diff_index: i8 = i8::try_from(3 + ally_index - enemy_index).unwrap() % 3;
+
+    let outcome_score = match diff_index {
+        0 => 3,
+        1 => 6,
+        _ => 0,
+    };
+
+    shape_score + outcome_score
+}
+
+fn index_of<T: std::cmp::PartialEq + Copy>(array: &[T], value: T) -> usize {
+    array.iter().position(|&r| r == value).unwrap()
+}
@@ -0,0 +1,70 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<parse::Line> = parse::lines(input).collect();
+        if lines.is_empty() {
+            return Err(ParseError::at_end(input, "a rucksack"));
+        }
+
+        for line in lines.iter() {
+            if let Some(index) = line.text.find(|char: char| !char.is_ascii_alphabetic()) {
+                return Err(line.error_at_char(index, "a letter"));
+            }
+            if !line.text.len().is_multiple_of(2) {
+                return Err(line.error("two compartments of the same size"));
+            }
+        }
+        if !lines.len().is_multiple_of(3) {
+            return Err(ParseError::at_end(input, "groups of three rucksacks"));
+        }
+
+        Ok(lines.iter().map(|line| String::from(line.text)).collect())
+    }
+
+    fn part1(rucksacks: &Self::Input) -> impl Display {
+        let mut total_priority = 0;
+
+        for rucksack in rucksacks.iter() {
+            let (first_compartment, second_compartment) = rucksack.split_at(rucksack.len() / 2);
+            let Some(duplicate) =
+                find_duplicate(first_compartment, second_compartment, second_compartment)
+            else {
+                return MaybeAnswer(None);
+            };
+            total_priority += get_letter_score(duplicate);
+        }
+
+        MaybeAnswer(Some(total_priority))
+    }
+
+    fn part2(rucksacks: &Self::Input) -> impl Display {
+        let mut total_priority = 0;
+
+        for group in rucksacks.chunks(3) {
+            let Some(duplicate) = find_duplicate(&group[0], &group[1], &group[2]) else {
+                return MaybeAnswer(None);
+            };
+            total_priority += get_letter_score(duplicate);
+        }
+
+        MaybeAnswer(Some(total_priority))
+    }
+}
+
+fn find_duplicate(first_part: &str, second_part: &str, third_part: &str) -> Option<char> {
+    first_part
+        .chars()
+        .find(|&element| second_part.contains(element) && third_part.contains(element))
+}
+
+fn get_letter_score(letter: char) -> usize {
+    ALPHABET.chars().position(|r| r == letter).unwrap() + 1
+}
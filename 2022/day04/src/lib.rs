@@ -0,0 +1,49 @@
+use aoc_core::{parse, Interval, ParseError, Solution};
+use std::fmt::Display;
+
+type Assignment = Interval<u32>;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Assignment, Assignment)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input).map(parse_line).collect()
+    }
+
+    fn part1(assignments: &Self::Input) -> impl Display {
+        assignments
+            .iter()
+            .filter(|(first, second)| {
+                first.contains_interval(second) || second.contains_interval(first)
+            })
+            .count()
+    }
+
+    fn part2(assignments: &Self::Input) -> impl Display {
+        assignments
+            .iter()
+            .filter(|(first, second)| first.intersects(second))
+            .count()
+    }
+}
+
+fn parse_line(line: parse::Line) -> Result<(Assignment, Assignment), ParseError> {
+    let Some((range1, range2)) = line.text.split_once(',') else {
+        return Err(line.error("two ranges like `2-4,6-8`"));
+    };
+    Ok((parse_range(&line, range1)?, parse_range(&line, range2)?))
+}
+
+fn parse_range(line: &parse::Line, range: &str) -> Result<Assignment, ParseError> {
+    let Some((start, end)) = range.split_once('-') else {
+        return Err(line.error_at(range, "a range like `2-4`"));
+    };
+    let start = line.parse::<u32>(start, "a section number")?;
+    let end_number = line.parse::<u32>(end, "a section number")?;
+    if end_number < start {
+        return Err(line.error_at(end, "a section number not below the start of the range"));
+    }
+    Ok(Interval::new(start, end_number))
+}
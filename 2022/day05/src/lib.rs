@@ -0,0 +1,117 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Pattern, Solution};
+use log::trace;
+use std::fmt::Display;
+
+pub struct Move {
+    count: usize,
+    origin_index: usize,
+    destination_index: usize,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Vec<char>>, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut is_parsing_crates: bool = true;
+        let mut raw_crates_lines: Vec<&str> = Vec::new();
+        let mut crate_stacks: Vec<Vec<char>> = Vec::new();
+        let mut moves: Vec<Move> = Vec::new();
+        let move_pattern = Pattern::new("move {count} from {stack} to {stack}").unwrap();
+
+        for line in parse::lines(input) {
+            if is_parsing_crates {
+                if !line.text.is_empty() {
+                    raw_crates_lines.push(line.text);
+                } else if raw_crates_lines.is_empty() {
+                    return Err(line.error("crate stacks"));
+                } else {
+                    let crates_count = (raw_crates_lines[0].len() + 1) / 4;
+                    for _crate_index in 0..crates_count {
+                        let crate_stack: Vec<char> = Vec::new();
+                        crate_stacks.push(crate_stack);
+                    }
+
+                    raw_crates_lines.reverse();
+                    for crate_line in raw_crates_lines[1..].iter() {
+                        for (index, crate_stack) in crate_stacks.iter_mut().enumerate() {
+                            let crate_name = crate_line.chars().nth(4 * index + 1).unwrap_or(' ');
+                            if crate_name != ' ' {
+                                crate_stack.push(crate_name);
+                            }
+                        }
+                    }
+
+                    is_parsing_crates = false;
+                }
+            } else {
+                let (count, origin, destination): (&str, &str, &str) = line.scan(&move_pattern)?;
+                moves.push(Move {
+                    count: line.parse::<usize>(count, "a crate count")?,
+                    origin_index: parse_stack_index(&line, origin, crate_stacks.len())?,
+                    destination_index: parse_stack_index(&line, destination, crate_stacks.len())?,
+                });
+            }
+        }
+
+        if is_parsing_crates {
+            return Err(ParseError::at_end(input, "an empty line followed by moves"));
+        }
+
+        Ok((crate_stacks, moves))
+    }
+
+    fn part1((crate_stacks, moves): &Self::Input) -> impl Display {
+        MaybeAnswer(rearrange(crate_stacks.clone(), moves, false))
+    }
+
+    fn part2((crate_stacks, moves): &Self::Input) -> impl Display {
+        MaybeAnswer(rearrange(crate_stacks.clone(), moves, true))
+    }
+}
+
+/// Parses a stack number, counted from 1, into its index.
+fn parse_stack_index(
+    line: &parse::Line,
+    stack_number: &str,
+    stack_count: usize,
+) -> Result<usize, ParseError> {
+    let expected = format!("a stack number from 1 to {stack_count}");
+    match line.parse::<usize>(stack_number, &expected)? {
+        0 => Err(line.error_at(stack_number, expected)),
+        number if number > stack_count => Err(line.error_at(stack_number, expected)),
+        number => Ok(number - 1),
+    }
+}
+
+/// Applies the moves and returns the crates on top of each stack. The CrateMover 9001 can
+/// move several crates at once, keeping their order. There is no answer when a move takes more
+/// crates than its stack holds, or when a stack ends up empty.
+fn rearrange(mut crate_stacks: Vec<Vec<char>>, moves: &[Move], keep_order: bool) -> Option<String> {
+    for crate_move in moves.iter() {
+        trace!(
+            "{} -> {} x {}",
+            crate_move.origin_index,
+            crate_move.destination_index,
+            crate_move.count
+        );
+
+        let mut temporary_stack: Vec<char> = Vec::new();
+        for _index in 0..crate_move.count {
+            temporary_stack.push(crate_stacks[crate_move.origin_index].pop()?);
+        }
+        if !keep_order {
+            temporary_stack.reverse();
+        }
+
+        for _index in 0..crate_move.count {
+            crate_stacks[crate_move.destination_index].push(temporary_stack.pop().unwrap());
+        }
+    }
+
+    crate_stacks
+        .iter()
+        .map(|crate_stack| crate_stack.last().copied())
+        .collect()
+}
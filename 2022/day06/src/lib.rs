@@ -0,0 +1,52 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+const START_OF_PACKET_LENGTH: usize = 4;
+const START_OF_MESSAGE_LENGTH: usize = 14;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "a datastream"))?;
+        let data = line.text.trim_end();
+
+        match data.find(|char: char| !char.is_ascii_lowercase()) {
+            Some(index) => Err(line.error_at_char(index, "a lowercase letter")),
+            None => Ok(String::from(data)),
+        }
+    }
+
+    fn part1(data: &Self::Input) -> impl Display {
+        MaybeAnswer(find_marker(data, START_OF_PACKET_LENGTH))
+    }
+
+    fn part2(data: &Self::Input) -> impl Display {
+        MaybeAnswer(find_marker(data, START_OF_MESSAGE_LENGTH))
+    }
+}
+
+/// Number of characters read once the last `marker_length` ones are all different.
+fn find_marker(data: &str, marker_length: usize) -> Option<usize> {
+    (marker_length..(data.len() + 1)).find(|&i| check_duplicate(&data[i - marker_length..i]))
+}
+
+fn check_duplicate(string: &str) -> bool {
+    for i in 0..string.len() {
+        let current_letter = at(string, i);
+        for j in i + 1..string.len() {
+            if current_letter == at(string, j) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn at(string: &str, index: usize) -> char {
+    string.chars().nth(index).unwrap()
+}
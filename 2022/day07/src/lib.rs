@@ -0,0 +1,114 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Pattern, Solution};
+use log::debug;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+const SMALL_DIRECTORY_SIZE: usize = 100000;
+const TOTAL_SPACE: usize = 70000000;
+const REQUIRED_SPACE: usize = 30000000;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let cd_pattern = Pattern::new("$ cd {directory}").unwrap();
+        let file_pattern = Pattern::new("{usize} {name}").unwrap();
+        let dir_pattern = Pattern::new("dir {name}").unwrap();
+
+        let mut current_path = String::from("");
+
+        let mut directory_size: HashMap<String, usize> = HashMap::new();
+
+        for line in parse::lines(input) {
+            let text = line.text;
+
+            if text == "$ cd /" {
+                current_path.push('/');
+                directory_size.insert(String::from("/"), 0);
+                continue;
+            }
+
+            if text == "$ cd .." {
+                current_path.pop();
+                while let Some(char) = current_path.pop() {
+                    if char == '/' {
+                        break;
+                    }
+                }
+                current_path.push('/');
+                continue;
+            }
+
+            if cd_pattern.is_match(text) {
+                let (directory_name,): (&str,) = line.scan(&cd_pattern)?;
+
+                current_path.push_str(directory_name);
+                current_path.push('/');
+                continue;
+            }
+
+            if dir_pattern.is_match(text) {
+                let (directory_name,): (&str,) = line.scan(&dir_pattern)?;
+
+                let directory_path = format!("{current_path}{directory_name}/");
+                directory_size.insert(directory_path, 0);
+                continue;
+            }
+
+            if file_pattern.is_match(text) {
+                let (size, _): (usize, &str) = line.scan(&file_pattern)?;
+
+                let mut new_directory_path = HashMap::new();
+                for (directory_path, directory_size) in directory_size {
+                    let mut new_size = directory_size;
+                    if current_path.starts_with(&directory_path) {
+                        new_size += size;
+                    }
+                    new_directory_path.insert(directory_path, new_size);
+                }
+                directory_size = new_directory_path;
+                continue;
+            }
+
+            if text != "$ ls" {
+                return Err(
+                    line.error("`$ cd <directory>`, `$ ls`, `dir <name>` or `<size> <name>`")
+                );
+            }
+        }
+
+        if !directory_size.contains_key("/") {
+            return Err(ParseError::at_end(input, "`$ cd /`"));
+        }
+        Ok(directory_size)
+    }
+
+    fn part1(directory_size: &Self::Input) -> impl Display {
+        directory_size
+            .values()
+            .filter(|&&size| size <= SMALL_DIRECTORY_SIZE)
+            .sum::<usize>()
+    }
+
+    fn part2(directory_size: &Self::Input) -> impl Display {
+        MaybeAnswer(get_space_to_free(directory_size))
+    }
+}
+
+/// Size of the smallest directory freeing enough space for the update, if the files fit on the
+/// disk but leave too little space.
+fn get_space_to_free(directory_size: &HashMap<String, usize>) -> Option<usize> {
+    let used_size = directory_size["/"];
+    let free_space = TOTAL_SPACE.checked_sub(used_size)?;
+    let perfect_space_to_free = REQUIRED_SPACE.checked_sub(free_space)?;
+
+    debug!("Directory sizes {directory_size:#?}");
+
+    directory_size
+        .values()
+        .filter(|&&size| size > perfect_space_to_free)
+        .min()
+        .copied()
+}
@@ -0,0 +1,64 @@
+use aoc_core::{Grid, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let forest = Grid::parse(input, "a tree height digit", |char| char.to_digit(10))?;
+        if forest.width() == 0 {
+            return Err(ParseError::at_end(input, "a tree height digit"));
+        }
+        Ok(forest)
+    }
+
+    fn part1(forest: &Self::Input) -> impl Display {
+        forest
+            .positions()
+            .filter(|&position| is_visible(forest, position))
+            .count()
+    }
+
+    fn part2(forest: &Self::Input) -> impl Display {
+        forest
+            .positions()
+            .map(|position| get_scenic_score(forest, position))
+            .max()
+            .unwrap()
+    }
+}
+
+fn is_visible(forest: &Grid<u32>, (x, y): (usize, usize)) -> bool {
+    let height = forest[(x, y)];
+    let is_shorter = |&tree: &u32| tree < height;
+    let row = forest.row(y);
+
+    row[..x].iter().all(is_shorter)
+        || row[x + 1..].iter().all(is_shorter)
+        || forest.column(x).take(y).all(is_shorter)
+        || forest.column(x).skip(y + 1).all(is_shorter)
+}
+
+fn get_scenic_score(forest: &Grid<u32>, (x, y): (usize, usize)) -> usize {
+    let height = forest[(x, y)];
+    let row = forest.row(y);
+
+    get_viewing_distance(row[..x].iter().rev(), height)
+        * get_viewing_distance(row[x + 1..].iter(), height)
+        * get_viewing_distance(forest.column(x).take(y).rev(), height)
+        * get_viewing_distance(forest.column(x).skip(y + 1), height)
+}
+
+/// Number of trees seen from a tree of the given height, looking along `trees`.
+fn get_viewing_distance<'a>(trees: impl Iterator<Item = &'a u32>, height: u32) -> usize {
+    let mut distance = 0;
+    for &tree in trees {
+        distance += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    distance
+}
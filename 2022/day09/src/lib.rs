@@ -0,0 +1,247 @@
+use aoc_core::render::{Animate, Animation, Frame, Rgb};
+use aoc_core::simulation::{Simulate, Simulation};
+use aoc_core::{parse, Dir4, Grid, ParseError, Point2, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::time::Duration;
+
+const SHORT_ROPE_LENGTH: usize = 2;
+const ROPE_LENGTH: usize = 10;
+const PIXELS_BY_POSITION: usize = 2;
+
+type Position = Point2<i64>;
+
+/// The long rope, its head moved one position at a time by the motions.
+#[derive(Clone)]
+pub struct Rope<'a> {
+    motions: &'a [(Dir4, u64)],
+    motion_index: usize,
+    /// Steps of the current motion already done.
+    step_count: u64,
+    knots: Vec<Position>,
+    tail_positions: HashSet<Position>,
+    /// Corners of the rectangle that the head, so every knot, stays in.
+    min: Position,
+    max: Position,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Dir4, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let Some((direction, steps)) = line.text.split_once(' ') else {
+                    return Err(line.error("a motion like `R 4`"));
+                };
+                let direction = match direction {
+                    "U" => Dir4::Up,
+                    "D" => Dir4::Down,
+                    "L" => Dir4::Left,
+                    "R" => Dir4::Right,
+                    _ => return Err(line.error_at(direction, "`U`, `D`, `L` or `R`")),
+                };
+                let steps = line.parse::<u64>(steps, "a number of steps")?;
+
+                Ok((direction, steps))
+            })
+            .collect()
+    }
+
+    fn part1(motions: &Self::Input) -> impl Display {
+        get_tail_positions_count(motions, SHORT_ROPE_LENGTH)
+    }
+
+    fn part2(motions: &Self::Input) -> impl Display {
+        get_tail_positions_count(motions, ROPE_LENGTH)
+    }
+}
+
+fn get_tail_positions_count(motions: &[(Dir4, u64)], rope_length: usize) -> usize {
+    let mut rope = vec![Position::new(0, 0); rope_length];
+
+    let mut tail_positions_set: HashSet<Position> = HashSet::new();
+    tail_positions_set.insert(rope[rope_length - 1]);
+
+    for (direction, steps) in motions.iter() {
+        for _ in 0..*steps {
+            move_rope(&mut rope, *direction);
+            tail_positions_set.insert(rope[rope_length - 1]);
+        }
+    }
+    tail_positions_set.len()
+}
+
+impl Animate for Day {
+    /// The long rope after each motion, over the positions visited by its tail.
+    fn animate(motions: &Self::Input) -> Animation {
+        let mut rope = vec![Position::new(0, 0); ROPE_LENGTH];
+        let mut ropes = vec![rope.clone()];
+        let mut tail_positions = vec![rope[ROPE_LENGTH - 1]];
+        let mut tail_position_counts = vec![1];
+
+        for (direction, steps) in motions.iter() {
+            for _ in 0..*steps {
+                move_rope(&mut rope, *direction);
+                tail_positions.push(rope[ROPE_LENGTH - 1]);
+            }
+            ropes.push(rope.clone());
+            tail_position_counts.push(tail_positions.len());
+        }
+
+        let all_positions = || ropes.iter().flatten().chain(&tail_positions);
+        let min_x = all_positions().map(|knot| knot.x).min().unwrap();
+        let max_x = all_positions().map(|knot| knot.x).max().unwrap();
+        let min_y = all_positions().map(|knot| knot.y).min().unwrap();
+        let max_y = all_positions().map(|knot| knot.y).max().unwrap();
+        let pixel =
+            |position: &Position| ((position.x - min_x) as usize, (position.y - min_y) as usize);
+
+        let mut animation = Animation::new(Duration::from_millis(20));
+        let mut trail = Frame::new(
+            (max_x - min_x + 1) as usize,
+            (max_y - min_y + 1) as usize,
+            Rgb::DARK_GREY,
+        );
+        let mut drawn_tail_position_count = 0;
+        for (rope, tail_position_count) in ropes.iter().zip(tail_position_counts) {
+            for tail_position in &tail_positions[drawn_tail_position_count..tail_position_count] {
+                trail.set(pixel(tail_position), Rgb::GREY);
+            }
+            drawn_tail_position_count = tail_position_count;
+
+            let mut frame = trail.clone();
+            for (index, knot) in rope.iter().enumerate().rev() {
+                let color = match index {
+                    0 => Rgb::RED,
+                    _ if index == ROPE_LENGTH - 1 => Rgb::GREEN,
+                    _ => Rgb::WHITE,
+                };
+                frame.set(pixel(knot), color);
+            }
+            animation.push(frame.scaled(PIXELS_BY_POSITION));
+        }
+
+        animation
+    }
+}
+
+impl Simulate for Day {
+    type Simulation<'a> = Rope<'a>;
+
+    fn simulate(motions: &Self::Input) -> Rope<'_> {
+        let start = Position::new(0, 0);
+        let (mut min, mut max) = (start, start);
+        let mut head = start;
+        for &(direction, steps) in motions {
+            for _ in 0..steps {
+                head = head.step(direction);
+            }
+            min = Position::new(min.x.min(head.x), min.y.min(head.y));
+            max = Position::new(max.x.max(head.x), max.y.max(head.y));
+        }
+
+        Rope {
+            motions,
+            motion_index: 0,
+            step_count: 0,
+            knots: vec![start; ROPE_LENGTH],
+            tail_positions: HashSet::from([start]),
+            min,
+            max,
+        }
+    }
+}
+
+impl Simulation for Rope<'_> {
+    /// Moves the head by one step of the current motion.
+    fn step(&mut self) -> bool {
+        while self
+            .motions
+            .get(self.motion_index)
+            .is_some_and(|&(_, steps)| self.step_count == steps)
+        {
+            self.motion_index += 1;
+            self.step_count = 0;
+        }
+        let Some(&(direction, _)) = self.motions.get(self.motion_index) else {
+            return false;
+        };
+
+        move_rope(&mut self.knots, direction);
+        self.tail_positions.insert(self.knots[ROPE_LENGTH - 1]);
+        self.step_count += 1;
+        true
+    }
+
+    fn render(&self) -> Grid<char> {
+        let mut grid = Grid::new(
+            (self.max.x - self.min.x + 1) as usize,
+            (self.max.y - self.min.y + 1) as usize,
+            '.',
+        );
+        let cell = |position: &Position| {
+            (
+                (position.x - self.min.x) as usize,
+                (position.y - self.min.y) as usize,
+            )
+        };
+
+        for tail_position in &self.tail_positions {
+            grid[cell(tail_position)] = '#';
+        }
+        grid[cell(&Position::new(0, 0))] = 's';
+        for (index, knot) in self.knots.iter().enumerate().rev() {
+            grid[cell(knot)] = match index {
+                0 => 'H',
+                _ => char::from_digit(index as u32, 10).unwrap(),
+            };
+        }
+        grid
+    }
+
+    fn inspect(&self, (x, y): (usize, usize)) -> String {
+        let position = self.min + Position::new(x as i64, y as i64);
+        let mut contents: Vec<String> = self
+            .knots
+            .iter()
+            .enumerate()
+            .filter(|&(_, knot)| *knot == position)
+            .map(|(index, _)| match index {
+                0 => String::from("head"),
+                _ if index == ROPE_LENGTH - 1 => String::from("tail"),
+                _ => format!("knot {index}"),
+            })
+            .collect();
+        if self.tail_positions.contains(&position) {
+            contents.push(String::from("visited by the tail"));
+        }
+        if contents.is_empty() {
+            contents.push(String::from("empty"));
+        }
+        format!("{position}: {}", contents.join(", "))
+    }
+}
+
+fn move_rope(rope: &mut [Position], direction: Dir4) {
+    let mut new_head_position = rope[0].step(direction);
+    rope[0] = new_head_position;
+
+    for tail_position in rope.iter_mut().skip(1) {
+        let new_tail_position = move_tail_rope(&new_head_position, *tail_position);
+        *tail_position = new_tail_position;
+        new_head_position = new_tail_position;
+    }
+}
+
+fn move_tail_rope(new_head_position: &Position, tail_position: Position) -> Position {
+    let distance = new_head_position.chebyshev_distance(tail_position);
+
+    if distance <= 1 {
+        return tail_position;
+    }
+
+    tail_position + (*new_head_position - tail_position).signum()
+}
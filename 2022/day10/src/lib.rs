@@ -0,0 +1,104 @@
+use aoc_core::render::{Animate, Animation, Frame, Rgb};
+use aoc_core::{parse, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+use std::time::Duration;
+
+const SIGNAL_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+const PIXELS_BY_CRT_PIXEL: usize = 8;
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Value of the X register during each cycle, starting from the first one.
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut x_history: Vec<i32> = vec![1];
+
+        for line in parse::lines(input) {
+            if line.text == "noop" {
+                x_history.push(*x_history.last().unwrap());
+            } else if let Some(added_value_text) = line.text.strip_prefix("addx ") {
+                let added_value = line.parse::<i32>(added_value_text, "a value to add")?;
+                let x = *x_history.last().unwrap();
+                let Some(new_x) = x.checked_add(added_value) else {
+                    return Err(line.error_at(added_value_text, "a value keeping X in range"));
+                };
+                x_history.push(x);
+                x_history.push(new_x);
+            } else {
+                return Err(line.error("`noop` or `addx <value>`"));
+            }
+        }
+
+        Ok(x_history)
+    }
+
+    fn part1(x_history: &Self::Input) -> impl Display {
+        let signal_strengths = SIGNAL_CYCLES
+            .iter()
+            .map(|&cycle| Some(cycle as i64 * *x_history.get(cycle - 1)? as i64))
+            .sum::<Option<i64>>();
+        MaybeAnswer(signal_strengths)
+    }
+
+    fn part2(x_history: &Self::Input) -> impl Display {
+        MaybeAnswer(draw_screen(x_history))
+    }
+}
+
+impl Animate for Day {
+    /// The CRT drawing the screen, one pixel every cycle.
+    fn animate(x_history: &Self::Input) -> Animation {
+        let mut animation = Animation::new(Duration::from_millis(30));
+        let mut screen = Frame::new(SCREEN_WIDTH, SCREEN_HEIGHT, Rgb::DARK_GREY);
+
+        for (index, &x) in x_history
+            .iter()
+            .enumerate()
+            .take(SCREEN_WIDTH * SCREEN_HEIGHT)
+        {
+            let pixel = (index % SCREEN_WIDTH, index / SCREEN_WIDTH);
+            if is_pixel_lit(index, x) {
+                screen.set(pixel, Rgb::GREEN);
+            }
+
+            let mut frame = screen.clone();
+            if !is_pixel_lit(index, x) {
+                frame.set(pixel, Rgb::RED);
+            }
+            animation.push(frame.scaled(PIXELS_BY_CRT_PIXEL));
+        }
+
+        animation
+    }
+}
+
+/// Whether the sprite, centered on `x`, covers the pixel drawn during a cycle.
+fn is_pixel_lit(index: usize, x: i32) -> bool {
+    let column = (index % SCREEN_WIDTH) as i32;
+    column - 1 <= x && x <= column + 1
+}
+
+/// The screen is only drawn when the instructions last long enough to draw every pixel.
+fn draw_screen(x_history: &[i32]) -> Option<String> {
+    if x_history.len() < SCREEN_WIDTH * SCREEN_HEIGHT {
+        return None;
+    }
+    let screen = x_history
+        .iter()
+        .enumerate()
+        .map(|(index, &x)| if is_pixel_lit(index, x) { '#' } else { '.' })
+        .collect::<String>();
+    let lines = [
+        &screen[0..40],
+        &screen[40..80],
+        &screen[80..120],
+        &screen[120..160],
+        &screen[160..200],
+        &screen[200..240],
+    ];
+    Some(lines.join("\n"))
+}
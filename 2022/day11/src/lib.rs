@@ -0,0 +1,259 @@
+use aoc_core::{number_theory, parse, MaybeAnswer, ParseError, Solution};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+const RELIEF_NUMBER_OF_ROUNDS: usize = 20;
+const RELIEF_FACTOR: i64 = 3;
+const NUMBER_OF_ROUNDS: usize = 10000;
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+enum OperationMember {
+    Constant(i64),
+    Old,
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    left_member: OperationMember,
+    operator: Operator,
+    right_member: OperationMember,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    item_list: VecDeque<i64>,
+    operation: Operation,
+    divisible_value_test: i64,
+    target_true: usize,
+    target_false: usize,
+    inspect_count: usize,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut monkey_informations: Vec<Vec<parse::Line>> = Vec::new();
+        let mut monkey_information: Vec<parse::Line> = Vec::new();
+        let line_count = input.lines().count();
+        let end_line = parse::Line {
+            number: line_count + 1,
+            text: "",
+        };
+        for line in parse::lines(input).chain([end_line]) {
+            if !line.text.is_empty() {
+                monkey_information.push(line);
+            } else if !monkey_information.is_empty() {
+                monkey_informations.push(monkey_information);
+                monkey_information = Vec::new();
+            }
+        }
+
+        monkey_informations
+            .iter()
+            .enumerate()
+            .map(|(monkey_index, monkey_information)| {
+                parse_monkey(monkey_index, monkey_informations.len(), monkey_information)
+            })
+            .collect()
+    }
+
+    fn part1(monkeys: &Self::Input) -> impl Display {
+        MaybeAnswer(get_monkey_business(
+            monkeys.clone(),
+            RELIEF_NUMBER_OF_ROUNDS,
+            true,
+        ))
+    }
+
+    fn part2(monkeys: &Self::Input) -> impl Display {
+        MaybeAnswer(get_monkey_business(
+            monkeys.clone(),
+            NUMBER_OF_ROUNDS,
+            false,
+        ))
+    }
+}
+
+/// Plays the rounds and multiplies the inspection counts of the two most active monkeys.
+/// Without relief, worry levels are kept low modulo the least common multiple of every test
+/// divisor. There is no monkey business without two monkeys.
+fn get_monkey_business(
+    mut monkeys: Vec<Monkey>,
+    number_of_rounds: usize,
+    relief: bool,
+) -> Option<usize> {
+    let monkey_common_multiple = number_theory::lcm_all(
+        monkeys
+            .iter()
+            .map(|monkey| monkey.divisible_value_test as u64),
+    ) as i64;
+
+    for _ in 0..number_of_rounds {
+        for monkey_index in 0..monkeys.len() {
+            let mut items_to_append_true: VecDeque<i64> = VecDeque::new();
+            let mut items_to_append_false: VecDeque<i64> = VecDeque::new();
+            let monkey_target_true;
+            let monkey_target_false;
+
+            {
+                let monkey = &mut monkeys[monkey_index];
+                monkey_target_true = monkey.target_true;
+                monkey_target_false = monkey.target_false;
+
+                while let Some(mut item_value) = monkey.item_list.pop_front() {
+                    monkey.inspect_count += 1;
+
+                    let left_member = match monkey.operation.left_member {
+                        OperationMember::Old => item_value,
+                        OperationMember::Constant(constant) => constant,
+                    };
+                    let right_member = match monkey.operation.right_member {
+                        OperationMember::Old => item_value,
+                        OperationMember::Constant(constant) => constant,
+                    };
+                    item_value = match monkey.operation.operator {
+                        Operator::Add => left_member + right_member,
+                        Operator::Multiply => left_member * right_member,
+                    };
+                    if relief {
+                        item_value /= RELIEF_FACTOR;
+                    } else {
+                        item_value %= monkey_common_multiple;
+                    }
+
+                    if item_value % monkey.divisible_value_test == 0 {
+                        items_to_append_true.push_back(item_value);
+                    } else {
+                        items_to_append_false.push_back(item_value);
+                    }
+                }
+            }
+
+            while let Some(item_value) = items_to_append_true.pop_front() {
+                monkeys[monkey_target_true].item_list.push_back(item_value);
+            }
+            while let Some(item_value) = items_to_append_false.pop_front() {
+                monkeys[monkey_target_false].item_list.push_back(item_value);
+            }
+        }
+    }
+
+    let mut monkey_inspection_counts: Vec<usize> =
+        monkeys.iter().map(|monkey| monkey.inspect_count).collect();
+    monkey_inspection_counts.sort();
+    monkey_inspection_counts.reverse();
+
+    match monkey_inspection_counts[..] {
+        [first_count, second_count, ..] => Some(first_count * second_count),
+        _ => None,
+    }
+}
+
+const MONKEY_LINE_PREFIXES: [&str; 6] = [
+    "Monkey ",
+    "  Starting items: ",
+    "  Operation: new = ",
+    "  Test: divisible by ",
+    "    If true: throw to monkey ",
+    "    If false: throw to monkey ",
+];
+
+fn parse_monkey(
+    monkey_index: usize,
+    monkey_count: usize,
+    monkey_information: &[parse::Line],
+) -> Result<Monkey, ParseError> {
+    let mut fields = Vec::new();
+    for (index, prefix) in MONKEY_LINE_PREFIXES.iter().enumerate() {
+        let Some(line) = monkey_information.get(index) else {
+            let last_line = monkey_information[monkey_information.len() - 1];
+            return Err(last_line.error_at_end(format!("a next line starting with `{prefix}`")));
+        };
+        match line.text.strip_prefix(prefix) {
+            Some(field) => fields.push((line, field)),
+            None => return Err(line.error(format!("a line starting with `{prefix}`"))),
+        }
+    }
+    if let Some(line) = monkey_information.get(MONKEY_LINE_PREFIXES.len()) {
+        return Err(line.error("an empty line between monkeys"));
+    }
+
+    let (line, items) = fields[1];
+    let item_list = items
+        .split(", ")
+        .map(|item| line.parse::<i64>(item, "a worry level"))
+        .collect::<Result<VecDeque<i64>, ParseError>>()?;
+
+    let (line, operation_data) = fields[2];
+    let operation = match operation_data.split(' ').collect::<Vec<&str>>()[..] {
+        [left_member, operator, right_member] => Operation {
+            left_member: parse_member(line, left_member)?,
+            right_member: parse_member(line, right_member)?,
+            operator: parse_operator(line, operator)?,
+        },
+        _ => return Err(line.error_at(operation_data, "an operation like `old * 19`")),
+    };
+
+    let (line, divisible_value_test) = fields[3];
+    let divisible_value_test = match line.parse::<i64>(divisible_value_test, "a divisor")? {
+        divisor if divisor <= 0 => {
+            return Err(line.error_at(divisible_value_test, "a positive divisor"))
+        }
+        divisor => divisor,
+    };
+
+    let target_true = parse_target(monkey_index, monkey_count, fields[4])?;
+    let target_false = parse_target(monkey_index, monkey_count, fields[5])?;
+
+    Ok(Monkey {
+        item_list,
+        operation,
+        divisible_value_test,
+        target_true,
+        target_false,
+        inspect_count: 0,
+    })
+}
+
+fn parse_member(line: &parse::Line, member: &str) -> Result<OperationMember, ParseError> {
+    match member {
+        "old" => Ok(OperationMember::Old),
+        _ => Ok(OperationMember::Constant(
+            line.parse::<i64>(member, "`old` or a number")?,
+        )),
+    }
+}
+
+fn parse_operator(line: &parse::Line, operator: &str) -> Result<Operator, ParseError> {
+    match operator {
+        "+" => Ok(Operator::Add),
+        "*" => Ok(Operator::Multiply),
+        _ => Err(line.error_at(operator, "`+` or `*`")),
+    }
+}
+
+/// A monkey could not throw an item to itself, nor to a monkey that does not exist.
+fn parse_target(
+    monkey_index: usize,
+    monkey_count: usize,
+    (line, target): (&parse::Line, &str),
+) -> Result<usize, ParseError> {
+    match line.parse::<usize>(target, "a monkey number")? {
+        target_index if target_index == monkey_index => {
+            Err(line.error_at(target, "another monkey than itself"))
+        }
+        target_index if target_index >= monkey_count => {
+            Err(line.error_at(target, format!("a monkey number below {monkey_count}")))
+        }
+        target_index => Ok(target_index),
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_core::{search, Grid, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+type Position = (usize, usize);
+
+pub struct HeightMap {
+    altitudes: Grid<u32>,
+    start_position: Position,
+    end_position: Position,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let letters = Grid::parse(input, "a lowercase letter, `S` or `E`", |letter| {
+            (letter.is_ascii_lowercase() || letter == 'S' || letter == 'E').then_some(letter)
+        })?;
+        let start_position = letters
+            .find(&'S')
+            .ok_or_else(|| ParseError::at_end(input, "a start position `S`"))?;
+        let end_position = letters
+            .find(&'E')
+            .ok_or_else(|| ParseError::at_end(input, "a best signal position `E`"))?;
+
+        Ok(HeightMap {
+            altitudes: letters.map(|&letter| letter_to_altitude(letter)),
+            start_position,
+            end_position,
+        })
+    }
+
+    fn part1(height_map: &Self::Input) -> impl Display {
+        let step_count =
+            find_minimal_step(&height_map.altitudes, height_map.end_position, |position| {
+                *position == height_map.start_position
+            });
+        MaybeAnswer(step_count)
+    }
+
+    fn part2(height_map: &Self::Input) -> impl Display {
+        MaybeAnswer(find_minimal_step(
+            &height_map.altitudes,
+            height_map.end_position,
+            |&position| height_map.altitudes[position] == 0,
+        ))
+    }
+}
+
+fn letter_to_altitude(letter: char) -> u32 {
+    if letter == 'S' {
+        return 0;
+    }
+    if letter == 'E' {
+        return 25;
+    }
+    letter as u32 - 97
+}
+
+/// Walks down from the best signal position until a position matching `is_target` is reached.
+fn find_minimal_step(
+    altitudes: &Grid<u32>,
+    start_position: Position,
+    is_target: impl Fn(&Position) -> bool,
+) -> Option<usize> {
+    search::bfs(
+        [start_position],
+        |&position| {
+            altitudes.neighbours4(position).filter(move |&neighbor| {
+                altitudes[neighbor] >= altitudes[position].saturating_sub(1)
+            })
+        },
+        is_target,
+    )
+    .map(|path| path.cost)
+}
@@ -0,0 +1,47 @@
+use crate::packet::Packet;
+use aoc_core::{parse, ParseError, Solution};
+use std::fmt::Display;
+
+pub mod packet;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let packets: Vec<Packet> = parse::lines(input)
+            .filter(|line| !line.text.is_empty())
+            .map(|line| Packet::parse(&line))
+            .collect::<Result<_, _>>()?;
+        if packets.len() % 2 == 1 {
+            return Err(ParseError::at_end(
+                input,
+                "a second packet for the last pair",
+            ));
+        }
+
+        Ok(packets)
+    }
+
+    fn part1(packets: &Self::Input) -> impl Display {
+        packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(index, _)| index + 1)
+            .sum::<usize>()
+    }
+
+    fn part2(packets: &Self::Input) -> impl Display {
+        let signal_2 = Packet::List(Vec::from([Packet::List(Vec::from([Packet::Integer(2)]))]));
+        let signal_6 = Packet::List(Vec::from([Packet::List(Vec::from([Packet::Integer(6)]))]));
+
+        // Once sorted, each divider comes after the packets smaller than it, and the second
+        // one also after the first.
+        let index_2 = packets.iter().filter(|&packet| packet < &signal_2).count() + 1;
+        let index_6 = packets.iter().filter(|&packet| packet < &signal_6).count() + 2;
+
+        index_2 * index_6
+    }
+}
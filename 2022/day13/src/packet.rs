@@ -1,3 +1,4 @@
+use aoc_core::{parse, ParseError};
 use std::cmp::min;
 use std::cmp::Ordering;
 
@@ -32,8 +33,12 @@ impl PartialEq for Packet {
 impl Eq for Packet {}
 
 impl Packet {
-    pub fn from(line: String) -> Packet {
-        parse_packet(line)
+    pub fn parse(line: &parse::Line) -> Result<Packet, ParseError> {
+        let (packet, end_index) = parse_packet_aux(line, 0)?;
+        if end_index + 1 < line.text.len() {
+            return Err(line.error_at(&line.text[end_index + 1..], "the end of the packet"));
+        }
+        Ok(packet)
     }
 }
 
@@ -62,7 +67,7 @@ fn are_integers_ordered(left_value: &u32, right_value: &u32) -> Option<bool> {
     }
 }
 
-fn are_lists_ordered(left_list: &Vec<Packet>, right_list: &Vec<Packet>) -> Option<bool> {
+fn are_lists_ordered(left_list: &[Packet], right_list: &[Packet]) -> Option<bool> {
     let min_len = min(left_list.len(), right_list.len());
     for index in 0..min_len {
         let are_element_ordered = are_ordered(&left_list[index], &right_list[index]);
@@ -76,50 +81,49 @@ fn are_lists_ordered(left_list: &Vec<Packet>, right_list: &Vec<Packet>) -> Optio
         return None;
     }
 
-    return Some(left_list.len() <= right_list.len());
-}
-
-fn parse_packet(line: String) -> Packet {
-    let (packet, _line_size) = parse_packet_aux(&line);
-    packet
+    Some(left_list.len() <= right_list.len())
 }
 
-fn parse_packet_aux(line: &str) -> (Packet, usize) {
-    if !line.starts_with('[') {
-        panic!("Packet does not start with `[`");
+/// Parses the list starting at the byte `start` of the line, returning it with the index of
+/// its closing `]`.
+fn parse_packet_aux(line: &parse::Line, start: usize) -> Result<(Packet, usize), ParseError> {
+    let text = line.text;
+    if !text[start..].starts_with('[') {
+        return Err(line.error_at_char(start, "`[`"));
     }
 
-    let mut index = 1;
+    let mut index = start + 1;
     let mut packet_list: Vec<Packet> = Vec::new();
 
-    let mut current_value = String::new();
+    let mut value_start = index;
 
-    while index < line.len() {
-        let char = line.as_bytes()[index] as char;
+    while index < text.len() {
+        let char = text.as_bytes()[index] as char;
 
         if char == '[' {
-            let (nested_packet, str_size) = parse_packet_aux(&line[index..]);
+            let (nested_packet, end_index) = parse_packet_aux(line, index)?;
             packet_list.push(nested_packet);
-            index += str_size + 1;
+            index = end_index + 1;
+            value_start = index;
             continue;
         }
 
         if char == ',' || char == ']' {
+            let current_value = &text[value_start..index];
             if !current_value.is_empty() {
-                let parsed_current_value = current_value.parse::<u32>().unwrap();
+                let parsed_current_value = line.parse::<u32>(current_value, "a number")?;
                 packet_list.push(Packet::Integer(parsed_current_value));
-                current_value = String::new();
             }
 
             if char == ']' {
-                return (Packet::List(packet_list), index);
+                return Ok((Packet::List(packet_list), index));
             }
             index += 1;
+            value_start = index;
             continue;
         }
-        current_value.push(char);
         index += 1;
     }
 
-    panic!("Error parsing packet.");
+    Err(line.error_at_end("`]`"))
 }
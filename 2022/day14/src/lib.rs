@@ -0,0 +1,301 @@
+use aoc_core::render::{Animate, Animation, Frame, Rgb};
+use aoc_core::simulation::{Simulate, Simulation};
+use aoc_core::{parse, Dir4, Grid, ParseError, Point2, Solution};
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::time::Duration;
+
+type Position = Point2<u32>;
+
+const SAND_SOURCE: Position = Position::new(500, 0);
+/// Grains of sand resting between two frames of the animation.
+const SANDS_BY_FRAME: usize = 100;
+const PIXELS_BY_POSITION: usize = 2;
+
+/// Sand falling one position at a time onto the floor, until it blocks the source.
+#[derive(Clone)]
+pub struct Cave<'a> {
+    walls: &'a HashSet<Position>,
+    sands: HashSet<Position>,
+    falling_sand: Option<Position>,
+    floor: u32,
+    min_x: u32,
+    max_x: u32,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<Position>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut walls = HashSet::new();
+        for line in parse::lines(input).filter(|line| !line.text.is_empty()) {
+            let corner_texts: Vec<&str> = line.text.split(" -> ").collect();
+            if corner_texts.len() < 2 {
+                return Err(line.error_at_end("` -> ` and a next corner"));
+            }
+            let corners = corner_texts
+                .iter()
+                .map(|corner| line.parse::<Position>(corner, "a corner like `498,4`"))
+                .collect::<Result<Vec<Position>, ParseError>>()?;
+            for (index, (start_position, end_position)) in
+                corners.iter().zip(corners.iter().skip(1)).enumerate()
+            {
+                let positions = get_intermediate_positions(start_position, end_position)
+                    .ok_or_else(|| {
+                        line.error_at(
+                            corner_texts[index + 1],
+                            "a corner in line with the previous one",
+                        )
+                    })?;
+                walls.extend(positions);
+            }
+        }
+
+        if walls.is_empty() {
+            return Err(ParseError::at_end(input, "a path of rock"));
+        }
+        Ok(walls)
+    }
+
+    fn part1(walls: &Self::Input) -> impl Display {
+        pour_sand(walls, false).len()
+    }
+
+    fn part2(walls: &Self::Input) -> impl Display {
+        let mut sands = pour_sand(walls, true);
+        sands.insert(SAND_SOURCE);
+
+        sands.len()
+    }
+}
+
+impl Animate for Day {
+    /// Sand piling up on the floor, until it blocks the source.
+    fn animate(walls: &Self::Input) -> Animation {
+        let cave = Cave::new(walls);
+        let pixel = |position: &Position| ((position.x - cave.min_x) as usize, position.y as usize);
+
+        let mut background = Frame::new(
+            (cave.max_x - cave.min_x + 1) as usize,
+            cave.floor as usize + 1,
+            Rgb::DARK_GREY,
+        );
+        for x in cave.min_x..=cave.max_x {
+            background.set(pixel(&Position::new(x, cave.floor)), Rgb::GREY);
+        }
+        for wall in walls {
+            background.set(pixel(wall), Rgb::GREY);
+        }
+
+        let mut animation = Animation::new(Duration::from_millis(40));
+        let mut frame = background;
+        let mut sands: HashSet<Position> = HashSet::new();
+        animation.push(frame.scaled(PIXELS_BY_POSITION));
+        while let Some(new_sand_position) =
+            get_new_sand_position(walls, &sands, cave.floor - 2, true)
+        {
+            sands.insert(new_sand_position);
+            frame.set(pixel(&new_sand_position), Rgb::YELLOW);
+            if sands.len().is_multiple_of(SANDS_BY_FRAME) {
+                animation.push(frame.scaled(PIXELS_BY_POSITION));
+            }
+        }
+        frame.set(pixel(&SAND_SOURCE), Rgb::YELLOW);
+        animation.push(frame.scaled(PIXELS_BY_POSITION));
+
+        animation
+    }
+}
+
+impl Simulate for Day {
+    type Simulation<'a> = Cave<'a>;
+
+    fn simulate(walls: &Self::Input) -> Cave<'_> {
+        Cave::new(walls)
+    }
+}
+
+impl<'a> Cave<'a> {
+    fn new(walls: &'a HashSet<Position>) -> Cave<'a> {
+        let deepest_wall = walls.iter().map(|wall| wall.y).max().unwrap();
+        let floor = deepest_wall + 2;
+        // Sand piles up in a triangle, no wider than twice the depth of the floor.
+        let min_x = min(
+            SAND_SOURCE.x - floor,
+            walls.iter().map(|wall| wall.x).min().unwrap(),
+        );
+        let max_x = max(
+            SAND_SOURCE.x + floor,
+            walls.iter().map(|wall| wall.x).max().unwrap(),
+        );
+
+        Cave {
+            walls,
+            sands: HashSet::new(),
+            falling_sand: None,
+            floor,
+            min_x,
+            max_x,
+        }
+    }
+}
+
+impl Simulation for Cave<'_> {
+    /// Moves the falling sand by one position, or pours the next one from the source.
+    fn step(&mut self) -> bool {
+        let Some(sand) = self.falling_sand else {
+            if self.sands.contains(&SAND_SOURCE) {
+                return false;
+            }
+            self.falling_sand = Some(SAND_SOURCE);
+            return true;
+        };
+
+        let next_sand = move_sand_one_step(&sand, &self.sands, self.walls);
+        if next_sand == sand || next_sand.y + 1 == self.floor {
+            self.sands.insert(next_sand);
+            self.falling_sand = None;
+        } else {
+            self.falling_sand = Some(next_sand);
+        }
+        true
+    }
+
+    fn render(&self) -> Grid<char> {
+        let mut grid = Grid::new(
+            (self.max_x - self.min_x + 1) as usize,
+            self.floor as usize + 1,
+            '.',
+        );
+        let cell = |position: &Position| ((position.x - self.min_x) as usize, position.y as usize);
+
+        grid[cell(&SAND_SOURCE)] = '+';
+        for x in self.min_x..=self.max_x {
+            grid[cell(&Position::new(x, self.floor))] = '#';
+        }
+        for wall in self.walls {
+            grid[cell(wall)] = '#';
+        }
+        for sand in &self.sands {
+            grid[cell(sand)] = 'o';
+        }
+        if let Some(sand) = self.falling_sand {
+            grid[cell(&sand)] = '~';
+        }
+        grid
+    }
+
+    fn inspect(&self, (x, y): (usize, usize)) -> String {
+        let position = Position::new(self.min_x + x as u32, y as u32);
+        let content = if self.falling_sand == Some(position) {
+            "falling sand"
+        } else if self.sands.contains(&position) {
+            "sand"
+        } else if self.walls.contains(&position) {
+            "rock"
+        } else if position.y == self.floor {
+            "floor"
+        } else if position == SAND_SOURCE {
+            "source"
+        } else {
+            "air"
+        };
+        format!("{position}: {content}")
+    }
+}
+
+/// Pours sand until it flows into the abyss, or until it blocks the source when there is a
+/// floor two units below the deepest wall.
+fn pour_sand(walls: &HashSet<Position>, has_floor: bool) -> HashSet<Position> {
+    let deepest_wall = walls.iter().map(|wall| wall.y).max().unwrap();
+
+    let mut sands: HashSet<Position> = HashSet::new();
+
+    while let Some(new_sand_position) =
+        get_new_sand_position(walls, &sands, deepest_wall, has_floor)
+    {
+        sands.insert(new_sand_position);
+    }
+
+    sands
+}
+
+/// Positions of the straight path between two corners, if they are aligned.
+fn get_intermediate_positions(
+    start_position: &Position,
+    end_position: &Position,
+) -> Option<Vec<Position>> {
+    if start_position.y == end_position.y {
+        return Some(
+            (min(start_position.x, end_position.x)..max(start_position.x, end_position.x) + 1)
+                .map(|x| Position::new(x, start_position.y))
+                .collect(),
+        );
+    }
+
+    if start_position.x == end_position.x {
+        return Some(
+            (min(start_position.y, end_position.y)..max(start_position.y, end_position.y) + 1)
+                .map(|y| Position::new(start_position.x, y))
+                .collect(),
+        );
+    }
+
+    None
+}
+
+fn get_new_sand_position(
+    sands: &HashSet<Position>,
+    walls: &HashSet<Position>,
+    deepest_wall: u32,
+    has_floor: bool,
+) -> Option<Position> {
+    let mut sand_position = SAND_SOURCE;
+
+    loop {
+        let next_sand_position = move_sand_one_step(&sand_position, sands, walls);
+
+        if next_sand_position == SAND_SOURCE {
+            return None;
+        }
+
+        if next_sand_position.y + 1 == deepest_wall + 2 {
+            return has_floor.then_some(next_sand_position);
+        }
+
+        if next_sand_position == sand_position {
+            return Some(sand_position);
+        }
+
+        sand_position = next_sand_position;
+    }
+}
+
+fn move_sand_one_step(
+    sand: &Position,
+    sands: &HashSet<Position>,
+    walls: &HashSet<Position>,
+) -> Position {
+    let bottom_position = sand.step(Dir4::Down);
+
+    if !sands.contains(&bottom_position) && !walls.contains(&bottom_position) {
+        return bottom_position;
+    }
+
+    let bottom_left_position = bottom_position.step(Dir4::Left);
+
+    if !sands.contains(&bottom_left_position) && !walls.contains(&bottom_left_position) {
+        return bottom_left_position;
+    }
+
+    let bottom_right_position = bottom_position.step(Dir4::Right);
+
+    if !sands.contains(&bottom_right_position) && !walls.contains(&bottom_right_position) {
+        return bottom_right_position;
+    }
+
+    *sand
+}
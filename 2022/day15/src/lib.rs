@@ -0,0 +1,121 @@
+use aoc_core::progress::ProgressIterator;
+use aoc_core::{parse, Interval, IntervalSet, MaybeAnswer, ParseError, Pattern, Point2, Solution};
+use log::{debug, trace};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+const TUNING_FREQUENCY: u128 = 4000000;
+const ROW: i32 = 2000000;
+const MAX_COORDINATES: i32 = 4000000;
+const EXAMPLE_SENSOR_COUNT: usize = 14;
+const EXAMPLE_ROW: i32 = 10;
+const EXAMPLE_MAX_COORDINATES: i32 = 20;
+
+type Position = Point2<i32>;
+
+pub struct Report {
+    sensors: Vec<(Position, Position)>,
+    row: i32,
+    max_coordinates: i32,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let pattern =
+            Pattern::new("Sensor at x={i32}, y={i32}: closest beacon is at x={i32}, y={i32}")
+                .unwrap();
+
+        let sensors = parse::lines(input)
+            .map(|line| {
+                trace!("{}", line.text);
+                let (sensor_x, sensor_y, beacon_x, beacon_y) = line.scan(&pattern)?;
+
+                Ok((
+                    Position::new(sensor_x, sensor_y),
+                    Position::new(beacon_x, beacon_y),
+                ))
+            })
+            .collect::<Result<Vec<(Position, Position)>, ParseError>>()?;
+
+        let (row, max_coordinates) = get_search_area(&sensors);
+
+        Ok(Report {
+            sensors,
+            row,
+            max_coordinates,
+        })
+    }
+
+    fn part1(report: &Self::Input) -> impl Display {
+        count_impossible_beacons(&report.sensors, report.row)
+    }
+
+    fn part2(report: &Self::Input) -> impl Display {
+        MaybeAnswer(find_tuning_frequency(report))
+    }
+}
+
+/// Row to count the impossible beacons on, and bounds of the coordinates of the distress beacon.
+fn get_search_area(sensors: &[(Position, Position)]) -> (i32, i32) {
+    if sensors.len() == EXAMPLE_SENSOR_COUNT {
+        (EXAMPLE_ROW, EXAMPLE_MAX_COORDINATES)
+    } else {
+        (ROW, MAX_COORDINATES)
+    }
+}
+
+fn count_impossible_beacons(sensors: &[(Position, Position)], row: i32) -> u32 {
+    let covered_intervals: IntervalSet<i32> = sensors
+        .iter()
+        .filter_map(|sensor| get_covered_interval(sensor, row))
+        .collect();
+
+    let beacons_on_row: HashSet<&Position> = sensors
+        .iter()
+        .map(|(_, beacon_position)| beacon_position)
+        .filter(|beacon_position| beacon_position.y == row)
+        .collect();
+
+    covered_intervals.len() as u32 - beacons_on_row.len() as u32
+}
+
+fn find_tuning_frequency(report: &Report) -> Option<u128> {
+    let bounds = Interval::new(0, report.max_coordinates);
+
+    for y in (0..report.max_coordinates + 1).progress("rows") {
+        let covered_intervals: IntervalSet<i32> = report
+            .sensors
+            .iter()
+            .filter_map(|sensor| get_covered_interval(sensor, y))
+            .collect();
+
+        if let Some(gap) = covered_intervals.complement(bounds).intervals().first() {
+            let x = gap.start;
+            debug!("Missing beacon at {x}, {y}");
+            return Some(x as u128 * TUNING_FREQUENCY + y as u128);
+        }
+    }
+
+    None
+}
+
+/// Positions of a row that are closer to the sensor than its beacon.
+fn get_covered_interval(
+    (sensor_position, beacon_position): &(Position, Position),
+    row: i32,
+) -> Option<Interval<i32>> {
+    let distance_sensor_beacon = sensor_position.manhattan_distance(*beacon_position);
+    let distance_sensor_row = (row - sensor_position.y).abs();
+
+    (distance_sensor_row <= distance_sensor_beacon).then(|| {
+        let diff_distances = distance_sensor_beacon - distance_sensor_row;
+        Interval::new(
+            sensor_position.x - diff_distances,
+            sensor_position.x + diff_distances,
+        )
+    })
+}
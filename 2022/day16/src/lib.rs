@@ -0,0 +1,274 @@
+use aoc_core::branch_and_bound::{self, Problem};
+use aoc_core::{parse, search, ParseError, Pattern, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Valve {
+    flow_rate: u32,
+    neighbor_valves: HashSet<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Possibility {
+    opened_valves: Vec<String>,
+    /// Valve where each agent still opening valves stands, with its remaining steps. The agent
+    /// with the most remaining steps comes first.
+    agents: Vec<(String, u32)>,
+    /// Flow released until the end by the opened valves.
+    total_flow: u32,
+}
+
+/// Agents opening the valves of the volcano, each move taking the first agent straight to the
+/// next valve it opens, or `None` to stop it from opening any other valve.
+struct Volcano<'a> {
+    valves: &'a HashMap<String, Valve>,
+    distances: HashMap<String, HashMap<String, u32>>,
+    /// Fewest steps between two different valves worth opening.
+    min_valve_distance: u32,
+}
+
+const ALONE_MAX_STEP: u32 = 30;
+const MAX_STEP: u32 = 26;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Valve>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_file(input)
+    }
+
+    fn part1(valves: &Self::Input) -> impl Display {
+        get_max_total_flow(valves, 1, ALONE_MAX_STEP)
+    }
+
+    fn part2(valves: &Self::Input) -> impl Display {
+        get_max_total_flow(valves, 2, MAX_STEP)
+    }
+}
+
+/// Best total flow for `agent_count` agents starting at `AA` to open valves.
+fn get_max_total_flow(valves: &HashMap<String, Valve>, agent_count: usize, max_step: u32) -> u32 {
+    let distances = get_distances(valves);
+    let min_valve_distance = distances
+        .iter()
+        .flat_map(|(from, distances)| {
+            distances
+                .iter()
+                .filter(move |(to, _)| *to != from)
+                .map(|(_, &distance)| distance)
+        })
+        .min()
+        .unwrap_or(0);
+    let volcano = Volcano {
+        valves,
+        distances,
+        min_valve_distance,
+    };
+    let initial_possibility = Possibility {
+        opened_valves: Vec::new(),
+        agents: vec![(String::from("AA"), max_step); agent_count],
+        total_flow: 0,
+    };
+
+    branch_and_bound::depth_first(&volcano, initial_possibility)
+        .unwrap()
+        .value
+}
+
+fn parse_file(input: &str) -> Result<HashMap<String, Valve>, ParseError> {
+    let mut valves: HashMap<String, Valve> = HashMap::new();
+    let mut tunnels = Vec::new();
+    let pattern =
+        Pattern::new("Valve {name} has flow rate={u32}; tunnels lead to valves {valves}").unwrap();
+    let single_tunnel_pattern =
+        Pattern::new("Valve {name} has flow rate={u32}; tunnel leads to valve {valve}").unwrap();
+
+    for line in parse::lines(input) {
+        let (valve_name, flow_rate, neighbor_list): (String, u32, &str) =
+            if single_tunnel_pattern.is_match(line.text) {
+                line.scan(&single_tunnel_pattern)?
+            } else {
+                line.scan(&pattern)?
+            };
+        let mut neighbor_valves: HashSet<String> = HashSet::new();
+        neighbor_list.split(", ").for_each(|neighbor_valve| {
+            neighbor_valves.insert(String::from(neighbor_valve));
+            tunnels.push((line, neighbor_valve));
+        });
+
+        valves.insert(
+            valve_name,
+            Valve {
+                flow_rate,
+                neighbor_valves,
+            },
+        );
+    }
+
+    // Tunnels are checked once every valve is known, as they may lead to later lines.
+    if let Some((line, neighbor_valve)) = tunnels
+        .into_iter()
+        .find(|(_, neighbor_valve)| !valves.contains_key(*neighbor_valve))
+    {
+        return Err(line.error_at(neighbor_valve, "the name of a valve"));
+    }
+    if !valves.contains_key("AA") {
+        return Err(ParseError::at_end(input, "the starting valve `AA`"));
+    }
+
+    Ok(valves)
+}
+
+impl Problem for Volcano<'_> {
+    type State = Possibility;
+    type Move = Option<String>;
+    type Value = u32;
+
+    fn successors(&self, possibility: &Possibility) -> Vec<(Option<String>, Possibility)> {
+        let Some((current_valve, remaining_step_count)) = possibility.agents.first() else {
+            return Vec::new();
+        };
+
+        let mut next_possibilities: Vec<(Option<String>, Possibility)> = self
+            .get_closed_valves(possibility)
+            .filter_map(|(valve_name, flow_rate)| {
+                // Valves out of reach of the agent are skipped.
+                let opening_step = self.distances[current_valve].get(valve_name)? + 1;
+                let flowing_step_count = remaining_step_count.checked_sub(opening_step)?;
+
+                let mut next_possibility = possibility.clone();
+                next_possibility.opened_valves.push(valve_name.clone());
+                next_possibility.opened_valves.sort();
+                next_possibility.agents[0] = (valve_name.clone(), flowing_step_count);
+                sort_agents(&mut next_possibility.agents);
+                next_possibility.total_flow += flow_rate * flowing_step_count;
+
+                Some((Some(valve_name.clone()), next_possibility))
+            })
+            .collect();
+
+        // Trying the valves releasing the most flow first finds good solutions early.
+        next_possibilities
+            .sort_by_key(|(_, next_possibility)| std::cmp::Reverse(next_possibility.total_flow));
+
+        if possibility.agents.len() > 1 {
+            let mut next_possibility = possibility.clone();
+            next_possibility.agents.remove(0);
+            next_possibilities.push((None, next_possibility));
+        }
+
+        next_possibilities
+    }
+
+    fn value(&self, possibility: &Possibility) -> Option<u32> {
+        Some(possibility.total_flow)
+    }
+
+    fn bound(&self, possibility: &Possibility) -> u32 {
+        possibility.total_flow + self.get_remaining_flow_upper_value(possibility)
+    }
+}
+
+impl Volcano<'_> {
+    /// Closed valves worth opening, with their flow rate.
+    fn get_closed_valves<'a>(
+        &'a self,
+        possibility: &'a Possibility,
+    ) -> impl Iterator<Item = (&'a String, u32)> + 'a {
+        self.valves
+            .iter()
+            .filter(|(valve_name, valve)| {
+                valve.flow_rate > 0 && !possibility.opened_valves.contains(valve_name)
+            })
+            .map(|(valve_name, valve)| (valve_name, valve.flow_rate))
+    }
+
+    /// Flow that the closed valves could still add. Each closed valve is opened at best right
+    /// after the closest agent reaches it. Each agent opens at best its first valve one step
+    /// after reaching the closest closed valve, which it may already stand on, then one valve
+    /// every time it walks the fewest steps between two valves.
+    fn get_remaining_flow_upper_value(&self, possibility: &Possibility) -> u32 {
+        let Some((_, max_remaining_step_count)) = possibility.agents.first() else {
+            return 0;
+        };
+        let closed_valves: Vec<(&String, u32)> = self.get_closed_valves(possibility).collect();
+
+        let closest_agent_upper_value = closed_valves
+            .iter()
+            .map(|(valve_name, flow_rate)| {
+                possibility
+                    .agents
+                    .iter()
+                    .filter_map(|(current_valve, remaining_step_count)| {
+                        let opening_step = 1 + self.distances[current_valve].get(*valve_name)?;
+                        Some(flow_rate * remaining_step_count.saturating_sub(opening_step))
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .sum();
+
+        let first_opening_step = 1 + possibility
+            .agents
+            .iter()
+            .flat_map(|(current_valve, _)| {
+                closed_valves
+                    .iter()
+                    .filter_map(|(valve_name, _)| self.distances[current_valve].get(*valve_name))
+            })
+            .min()
+            .unwrap_or(&0);
+        let mut flow_rates: Vec<u32> = closed_valves
+            .iter()
+            .map(|(_, flow_rate)| *flow_rate)
+            .collect();
+        flow_rates.sort_unstable_by(|a, b| b.cmp(a));
+        let opening_rhythm_upper_value = flow_rates
+            .into_iter()
+            .enumerate()
+            .map(|(index, flow_rate)| {
+                let opening_step = first_opening_step
+                    + (1 + self.min_valve_distance) * (index / possibility.agents.len()) as u32;
+                flow_rate * max_remaining_step_count.saturating_sub(opening_step)
+            })
+            .sum();
+
+        std::cmp::min(closest_agent_upper_value, opening_rhythm_upper_value)
+    }
+}
+
+/// Agents with the most remaining steps first, so that agents swapping places give the same
+/// possibility.
+fn sort_agents(agents: &mut [(String, u32)]) {
+    agents.sort_by(|(valve, step_count), (other_valve, other_step_count)| {
+        other_step_count
+            .cmp(step_count)
+            .then_with(|| valve.cmp(other_valve))
+    });
+}
+
+/// Step count of the shortest path from the valves where an agent may stand, `AA` and the
+/// ones worth opening, to each valve worth opening that it can reach.
+fn get_distances(valves: &HashMap<String, Valve>) -> HashMap<String, HashMap<String, u32>> {
+    let neighbours = |valve: &&String| valves[*valve].neighbor_valves.iter();
+    let worth_opening = || valves.iter().filter(|(_, valve)| valve.flow_rate > 0);
+
+    worth_opening()
+        .map(|(valve_name, _)| valve_name)
+        .chain(valves.get_key_value("AA").map(|(valve_name, _)| valve_name))
+        .map(|start_valve| {
+            let distances = worth_opening()
+                .filter_map(|(valve_name, _)| {
+                    let path =
+                        search::bfs([start_valve], neighbours, |&valve| valve == valve_name)?;
+                    Some((valve_name.clone(), path.cost as u32))
+                })
+                .collect();
+            (start_valve.clone(), distances)
+        })
+        .collect()
+}
@@ -0,0 +1,232 @@
+use aoc_core::render::{Animate, Animation, Frame, Rgb};
+use aoc_core::{cycle, parse, ParseError, Point2, Solution};
+use std::cmp::max;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum Wind {
+    Left,
+    Right,
+}
+
+/// Position in the chamber, `y` growing upwards from the floor at 0.
+type Position = Point2<u64>;
+
+const ONE_RIGHT: Position = Position::new(1, 0);
+const ONE_UP: Position = Position::new(0, 1);
+
+/// Shapes of the falling rocks, in their order, from their bottom left corner.
+const ROCKS: [&[(u64, u64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+/// Rocks fallen in the chamber, and where the next one starts.
+struct Chamber<'a> {
+    winds: &'a [Wind],
+    wind_index: usize,
+    rock_count: u64,
+    rested_rocks: HashSet<Position>,
+    /// Highest rested rock in each column, from 1 to 7.
+    column_heights: [u64; 7],
+    max_y: u64,
+}
+
+/// What the next rocks depend on: the next wind and rock, and the top of each column relative
+/// to the highest one.
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct ChamberKey {
+    wind_index: usize,
+    rock_type: u64,
+    column_depths: [u64; 7],
+}
+
+const SHORT_NUMBER_OF_ROCKS: u64 = 2022;
+const NUMBER_OF_ROCKS: u64 = 1000000000000;
+const ANIMATED_NUMBER_OF_ROCKS: u64 = 100;
+/// Rows of the chamber shown in each frame, up to the top of the falling rock.
+const ANIMATED_HEIGHT: u64 = 40;
+const PIXELS_BY_POSITION: usize = 8;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Wind>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_winds(input)
+    }
+
+    fn part1(winds: &Self::Input) -> impl Display {
+        get_tower_height(winds, SHORT_NUMBER_OF_ROCKS)
+    }
+
+    fn part2(winds: &Self::Input) -> impl Display {
+        get_tower_height(winds, NUMBER_OF_ROCKS)
+    }
+}
+
+impl Animate for Day {
+    /// The first rocks falling, one frame for each push of a jet.
+    fn animate(winds: &Self::Input) -> Animation {
+        let mut animation = Animation::new(Duration::from_millis(30));
+        let mut chamber = Chamber::new(winds);
+
+        for _ in 0..ANIMATED_NUMBER_OF_ROCKS {
+            chamber.drop_rock_watched(|rested_rocks, rock| {
+                animation.push(draw_chamber(rested_rocks, rock));
+            });
+        }
+
+        animation
+    }
+}
+
+/// Top of the chamber, with the walls, the floor, the rested rocks and the falling one.
+fn draw_chamber(rested_rocks: &HashSet<Position>, rock: &[Position]) -> Frame {
+    let rock_top = rock.iter().map(|fragment| fragment.y).max().unwrap();
+    let top = max(rock_top, ANIMATED_HEIGHT - 1);
+
+    let mut frame = Frame::new(9, ANIMATED_HEIGHT as usize, Rgb::DARK_GREY);
+    for row in 0..ANIMATED_HEIGHT {
+        let y = top - row;
+        for x in 0..9 {
+            let position = Position::new(x, y);
+            let color = if x == 0 || x == 8 || y == 0 {
+                Rgb::GREY
+            } else if rested_rocks.contains(&position) {
+                Rgb::BLUE
+            } else if rock.contains(&position) {
+                Rgb::ORANGE
+            } else {
+                continue;
+            };
+            frame.set((x as usize, row as usize), color);
+        }
+    }
+
+    frame.scaled(PIXELS_BY_POSITION)
+}
+
+fn get_tower_height(winds: &[Wind], number_of_rocks: u64) -> u64 {
+    cycle::extrapolate(
+        Chamber::new(winds),
+        Chamber::drop_rock,
+        Chamber::key,
+        |chamber| chamber.max_y as i64,
+        number_of_rocks,
+    ) as u64
+}
+
+impl<'a> Chamber<'a> {
+    fn new(winds: &'a [Wind]) -> Chamber<'a> {
+        Chamber {
+            winds,
+            wind_index: 0,
+            rock_count: 0,
+            rested_rocks: HashSet::new(),
+            column_heights: [0; 7],
+            max_y: 0,
+        }
+    }
+
+    fn key(&self) -> ChamberKey {
+        ChamberKey {
+            wind_index: self.wind_index,
+            rock_type: self.rock_count % 5,
+            column_depths: self.column_heights.map(|height| self.max_y - height),
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        self.drop_rock_watched(|_, _| {});
+    }
+
+    /// Drops a rock, calling `watch` with the rested rocks and the falling one before every
+    /// push of a jet.
+    fn drop_rock_watched(&mut self, mut watch: impl FnMut(&HashSet<Position>, &[Position])) {
+        let rock_origin = Position::new(3, self.max_y + 4);
+        let mut rock = get_new_rock(rock_origin, self.rock_count);
+        self.rock_count += 1;
+
+        loop {
+            watch(&self.rested_rocks, &rock);
+            let mut rock_after_wind = match self.winds[self.wind_index] {
+                Wind::Left => move_rock_left(&rock),
+                Wind::Right => move_rock_right(&rock),
+            };
+            self.wind_index += 1;
+            self.wind_index %= self.winds.len();
+            if has_collision(&rock_after_wind, &self.rested_rocks) {
+                rock_after_wind = rock;
+            }
+
+            let rock_after_fall = move_rock_bottom(&rock_after_wind);
+            if has_collision(&rock_after_fall, &self.rested_rocks) {
+                for fragment in &rock_after_wind {
+                    let column_height = &mut self.column_heights[fragment.x as usize - 1];
+                    *column_height = max(*column_height, fragment.y);
+                    self.max_y = max(self.max_y, fragment.y);
+                }
+                self.rested_rocks.extend(rock_after_wind);
+                return;
+            }
+
+            rock = rock_after_fall;
+        }
+    }
+}
+
+fn parse_winds(input: &str) -> Result<Vec<Wind>, ParseError> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, "a jet pattern"))?;
+
+    let pattern = line.text.trim_end();
+    if pattern.is_empty() {
+        return Err(line.error_at_end("a jet pattern"));
+    }
+
+    pattern
+        .char_indices()
+        .map(|(index, char)| match char {
+            '<' => Ok(Wind::Left),
+            '>' => Ok(Wind::Right),
+            _ => Err(line.error_at_char(index, "`<` or `>`")),
+        })
+        .collect()
+}
+
+fn get_new_rock(new_rock_origin: Position, rock_count: u64) -> Vec<Position> {
+    ROCKS[(rock_count % 5) as usize]
+        .iter()
+        .map(|&(x, y)| new_rock_origin + Position::new(x, y))
+        .collect()
+}
+
+fn has_collision(rock: &[Position], rested_rocks: &HashSet<Position>) -> bool {
+    rock.iter().any(|rock_frag| {
+        rock_frag.x == 0 || rock_frag.x == 8 || rock_frag.y == 0 || rested_rocks.contains(rock_frag)
+    })
+}
+
+fn move_rock_bottom(rock: &[Position]) -> Vec<Position> {
+    rock.iter().map(|&rock_frag| rock_frag - ONE_UP).collect()
+}
+
+fn move_rock_left(rock: &[Position]) -> Vec<Position> {
+    rock.iter()
+        .map(|&rock_frag| rock_frag - ONE_RIGHT)
+        .collect()
+}
+
+fn move_rock_right(rock: &[Position]) -> Vec<Position> {
+    rock.iter()
+        .map(|&rock_frag| rock_frag + ONE_RIGHT)
+        .collect()
+}
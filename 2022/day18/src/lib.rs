@@ -0,0 +1,129 @@
+use aoc_core::{parse, search, ParseError, Point3, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+
+type Droplet = Point3<i64>;
+
+#[derive(Debug, Hash, Eq, PartialEq)]
+enum Side {
+    X,
+    Y,
+    Z,
+}
+
+/// Facet on the `side` of the cube at `position`, facing towards lower coordinates.
+#[derive(Debug, Hash, Eq, PartialEq)]
+struct Facet {
+    position: Droplet,
+    side: Side,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<Droplet>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_droplets(input)
+    }
+
+    fn part1(droplets: &Self::Input) -> impl Display {
+        get_facets(droplets).len()
+    }
+
+    fn part2(droplets: &Self::Input) -> impl Display {
+        get_facets_count_using_inverted_droplets(droplets)
+    }
+}
+
+fn parse_droplets(input: &str) -> Result<HashSet<Droplet>, ParseError> {
+    let mut droplets: HashSet<Droplet> = HashSet::new();
+
+    for line in parse::lines(input) {
+        droplets.insert(line.parse(line.text, "three coordinates like `2,2,2`")?);
+    }
+
+    Ok(droplets)
+}
+
+fn get_facets_from_droplet(droplet: &Droplet) -> Vec<Facet> {
+    Vec::from([
+        (*droplet, Side::X),
+        (*droplet, Side::Y),
+        (*droplet, Side::Z),
+        (*droplet + Droplet::new(1, 0, 0), Side::X),
+        (*droplet + Droplet::new(0, 1, 0), Side::Y),
+        (*droplet + Droplet::new(0, 0, 1), Side::Z),
+    ])
+    .into_iter()
+    .map(|(position, side)| Facet { position, side })
+    .collect()
+}
+
+fn get_facets(droplets: &HashSet<Droplet>) -> HashSet<Facet> {
+    let mut facets: HashSet<Facet> = HashSet::new();
+
+    for droplet in droplets.iter() {
+        for facet in get_facets_from_droplet(droplet).into_iter() {
+            if facets.contains(&facet) {
+                facets.remove(&facet);
+            } else {
+                facets.insert(facet);
+            }
+        }
+    }
+
+    facets
+}
+
+fn get_facets_count_using_inverted_droplets(droplets: &HashSet<Droplet>) -> usize {
+    let max_x = droplets.iter().map(|droplet| droplet.x).max().unwrap() + 1;
+    let max_y = droplets.iter().map(|droplet| droplet.y).max().unwrap() + 1;
+    let max_z = droplets.iter().map(|droplet| droplet.z).max().unwrap() + 1;
+
+    let inverted_droplets = search::reachable([Droplet::new(0, 0, 0)], |droplet| {
+        get_neighbors(droplet, max_x, max_y, max_z)
+            .into_iter()
+            .filter(|neighbor| !droplets.contains(neighbor))
+    });
+
+    let droplet_without_holes = get_inverted_droplets(&inverted_droplets, max_x, max_y, max_z);
+    let facets_without_holes = get_facets(&droplet_without_holes);
+
+    facets_without_holes.len()
+}
+
+fn get_neighbors(droplet: &Droplet, max_x: i64, max_y: i64, max_z: i64) -> Vec<Droplet> {
+    droplet
+        .neighbours6()
+        .into_iter()
+        .filter(|neighbor| {
+            (0..=max_x).contains(&neighbor.x)
+                && (0..=max_y).contains(&neighbor.y)
+                && (0..=max_z).contains(&neighbor.z)
+        })
+        .collect()
+}
+
+fn get_inverted_droplets(
+    droplets: &HashSet<Droplet>,
+    max_x: i64,
+    max_y: i64,
+    max_z: i64,
+) -> HashSet<Droplet> {
+    let mut inverted_droplet: HashSet<Droplet> = HashSet::new();
+
+    for x in 0..max_x + 1 {
+        for y in 0..max_y + 1 {
+            for z in 0..max_z + 1 {
+                let droplet = Droplet::new(x, y, z);
+                if !droplets.contains(&droplet) {
+                    inverted_droplet.insert(droplet);
+                }
+            }
+        }
+    }
+
+    inverted_droplet
+}
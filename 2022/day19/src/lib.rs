@@ -0,0 +1,338 @@
+use aoc_core::branch_and_bound::{self, Problem};
+use aoc_core::{parse, ParseError, Pattern, Solution};
+use log::debug;
+use std::fmt::Display;
+use std::thread;
+
+#[derive(Debug)]
+struct Cost {
+    ore: u64,
+    clay: u64,
+    obsidian: u64,
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u64,
+    ore_robot_cost: Cost,
+    clay_robot_cost: Cost,
+    obsidian_robot_cost: Cost,
+    geode_robot_cost: Cost,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Robot {
+    /// Robots building up the production first, which finds good solutions early.
+    const ALL: [Robot; 4] = [Robot::Ore, Robot::Clay, Robot::Obsidian, Robot::Geode];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ProductionState {
+    minute: u64,
+    max_minute: u64,
+    ore_robot_count: u64,
+    clay_robot_count: u64,
+    obsidian_robot_count: u64,
+    geode_robot_count: u64,
+    ore_count: u64,
+    clay_count: u64,
+    obsidian_count: u64,
+    geode_count: u64,
+}
+
+/// Robot factory searching the most geodes it can open with a blueprint.
+struct Factory<'a> {
+    blueprint: &'a Blueprint,
+}
+
+const SHORT_MAX_MINUTES: u64 = 24;
+const MAX_MINUTES: u64 = 32;
+const UNEATEN_BLUEPRINT_COUNT: usize = 3;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_blueprints(input)
+    }
+
+    fn part1(blueprints: &Self::Input) -> impl Display {
+        let max_geode_counts = find_max_geode_counts(blueprints, SHORT_MAX_MINUTES);
+
+        blueprints
+            .iter()
+            .zip(max_geode_counts)
+            .map(|(blueprint, max_geode_count)| blueprint.id * max_geode_count)
+            .sum::<u64>()
+    }
+
+    fn part2(blueprints: &Self::Input) -> impl Display {
+        let uneaten_blueprints = &blueprints[..blueprints.len().min(UNEATEN_BLUEPRINT_COUNT)];
+
+        find_max_geode_counts(uneaten_blueprints, MAX_MINUTES)
+            .into_iter()
+            .product::<u64>()
+    }
+}
+
+fn find_max_geode_counts(blueprints: &[Blueprint], max_minute: u64) -> Vec<u64> {
+    thread::scope(|scope| {
+        let handles: Vec<_> = blueprints
+            .iter()
+            .map(|blueprint| scope.spawn(move || find_max_geode_count(blueprint, max_minute)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|process| process.join().unwrap())
+            .collect()
+    })
+}
+
+fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let pattern = Pattern::new(concat!(
+        "Blueprint {u64}: Each ore robot costs {u64} ore. Each clay robot costs {u64} ore. ",
+        "Each obsidian robot costs {u64} ore and {u64} clay. ",
+        "Each geode robot costs {u64} ore and {u64} obsidian."
+    ))
+    .unwrap();
+
+    let mut blueprints: Vec<Blueprint> = Vec::new();
+
+    for line in parse::lines(input) {
+        let (
+            id,
+            ore_robot_ore,
+            clay_robot_ore,
+            obsidian_robot_ore,
+            clay,
+            geode_robot_ore,
+            obsidian,
+        ) = line.scan(&pattern)?;
+
+        blueprints.push(Blueprint {
+            id,
+            ore_robot_cost: Cost {
+                ore: ore_robot_ore,
+                clay: 0,
+                obsidian: 0,
+            },
+            clay_robot_cost: Cost {
+                ore: clay_robot_ore,
+                clay: 0,
+                obsidian: 0,
+            },
+            obsidian_robot_cost: Cost {
+                ore: obsidian_robot_ore,
+                clay,
+                obsidian: 0,
+            },
+            geode_robot_cost: Cost {
+                ore: geode_robot_ore,
+                clay: 0,
+                obsidian,
+            },
+        });
+    }
+
+    Ok(blueprints)
+}
+
+fn find_max_geode_count(blueprint: &Blueprint, max_minute: u64) -> u64 {
+    let initial_production_state = ProductionState {
+        minute: 0,
+        max_minute,
+        ore_robot_count: 1,
+        clay_robot_count: 0,
+        obsidian_robot_count: 0,
+        geode_robot_count: 0,
+        ore_count: 0,
+        clay_count: 0,
+        obsidian_count: 0,
+        geode_count: 0,
+    };
+
+    let factory = Factory { blueprint };
+    let max_geode_produced = branch_and_bound::depth_first(&factory, initial_production_state)
+        .unwrap()
+        .value;
+    debug!(
+        "[#{}]\tMax geode produced {max_geode_produced}",
+        blueprint.id
+    );
+
+    max_geode_produced
+}
+
+/// Each move builds the next robot, waiting until it can be paid. Waiting until the end without
+/// building any other robot is always possible.
+impl Problem for Factory<'_> {
+    type State = ProductionState;
+    type Move = Robot;
+    type Value = u64;
+
+    fn successors(&self, production_state: &ProductionState) -> Vec<(Robot, ProductionState)> {
+        Robot::ALL
+            .into_iter()
+            .filter(|&robot| self.blueprint.is_robot_required(production_state, robot))
+            .filter_map(|robot| {
+                let cost = self.blueprint.get_cost(robot);
+                let required_minutes =
+                    get_required_minutes_until_cost_reached(production_state, cost)?;
+                if production_state.minute + required_minutes >= production_state.max_minute {
+                    return None;
+                }
+
+                let mut new_production_state = *production_state;
+                pass_time(&mut new_production_state, required_minutes);
+                pay_cost(&mut new_production_state, cost);
+                *new_production_state.get_robot_count_mut(robot) += 1;
+                Some((robot, new_production_state))
+            })
+            .collect()
+    }
+
+    fn value(&self, production_state: &ProductionState) -> Option<u64> {
+        let remaining_minutes = production_state.max_minute - production_state.minute;
+        Some(production_state.geode_count + production_state.geode_robot_count * remaining_minutes)
+    }
+
+    fn bound(&self, production_state: &ProductionState) -> u64 {
+        get_max_geode_count_greater_value(production_state)
+    }
+}
+
+impl Blueprint {
+    fn get_cost(&self, robot: Robot) -> &Cost {
+        match robot {
+            Robot::Ore => &self.ore_robot_cost,
+            Robot::Clay => &self.clay_robot_cost,
+            Robot::Obsidian => &self.obsidian_robot_cost,
+            Robot::Geode => &self.geode_robot_cost,
+        }
+    }
+
+    /// Only one robot can be built each minute, so producing more of a resource than the most
+    /// expensive robot needs is useless.
+    fn is_robot_required(&self, production_state: &ProductionState, robot: Robot) -> bool {
+        let resource = |cost: &Cost| match robot {
+            Robot::Ore => cost.ore,
+            Robot::Clay => cost.clay,
+            Robot::Obsidian => cost.obsidian,
+            Robot::Geode => u64::MAX,
+        };
+
+        Robot::ALL.into_iter().any(|other_robot| {
+            production_state.get_robot_count(robot) < resource(self.get_cost(other_robot))
+        })
+    }
+}
+
+impl ProductionState {
+    fn get_robot_count(&self, robot: Robot) -> u64 {
+        match robot {
+            Robot::Ore => self.ore_robot_count,
+            Robot::Clay => self.clay_robot_count,
+            Robot::Obsidian => self.obsidian_robot_count,
+            Robot::Geode => self.geode_robot_count,
+        }
+    }
+
+    fn get_robot_count_mut(&mut self, robot: Robot) -> &mut u64 {
+        match robot {
+            Robot::Ore => &mut self.ore_robot_count,
+            Robot::Clay => &mut self.clay_robot_count,
+            Robot::Obsidian => &mut self.obsidian_robot_count,
+            Robot::Geode => &mut self.geode_robot_count,
+        }
+    }
+}
+
+fn pass_time(production_state: &mut ProductionState, minute_count: u64) {
+    production_state.minute += minute_count;
+    production_state.ore_count += production_state.ore_robot_count * minute_count;
+    production_state.clay_count += production_state.clay_robot_count * minute_count;
+    production_state.obsidian_count += production_state.obsidian_robot_count * minute_count;
+    production_state.geode_count += production_state.geode_robot_count * minute_count;
+}
+
+fn pay_cost(production_state: &mut ProductionState, cost: &Cost) {
+    production_state.ore_count -= cost.ore;
+    production_state.clay_count -= cost.clay;
+    production_state.obsidian_count -= cost.obsidian;
+}
+
+fn get_required_minutes_until_cost_reached(
+    production_state: &ProductionState,
+    cost: &Cost,
+) -> Option<u64> {
+    if (cost.ore > 0 && production_state.ore_robot_count == 0)
+        || (cost.clay > 0 && production_state.clay_robot_count == 0)
+        || (cost.obsidian > 0 && production_state.obsidian_robot_count == 0)
+    {
+        return None;
+    }
+
+    let required_minutes_for_ore = if production_state.ore_count >= cost.ore {
+        1
+    } else {
+        ceiled_division(
+            cost.ore - production_state.ore_count,
+            production_state.ore_robot_count,
+        ) + 1
+    };
+    let required_minutes_for_clay = if production_state.clay_count >= cost.clay {
+        1
+    } else {
+        ceiled_division(
+            cost.clay - production_state.clay_count,
+            production_state.clay_robot_count,
+        ) + 1
+    };
+    let required_minutes_for_obsidian = if production_state.obsidian_count >= cost.obsidian {
+        1
+    } else {
+        ceiled_division(
+            cost.obsidian - production_state.obsidian_count,
+            production_state.obsidian_robot_count,
+        ) + 1
+    };
+
+    let required_minutes = Vec::from([
+        required_minutes_for_ore,
+        required_minutes_for_clay,
+        required_minutes_for_obsidian,
+    ])
+    .into_iter()
+    .max()
+    .unwrap();
+
+    Some(required_minutes)
+}
+
+fn ceiled_division(x: u64, y: u64) -> u64 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    x.div_ceil(y)
+}
+
+fn get_max_geode_count_greater_value(production_state: &ProductionState) -> u64 {
+    let remaining_minutes = production_state.max_minute - production_state.minute;
+    let geode_robot_count = production_state.geode_robot_count;
+    let geode_count = production_state.geode_count;
+
+    geode_count // Initial number of geode
+        + geode_robot_count * remaining_minutes // Current robot production
+        + remaining_minutes * (remaining_minutes + 1) / 2 // Production if each remaining minute produces a robot
+}
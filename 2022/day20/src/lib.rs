@@ -0,0 +1,101 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+#[derive(Clone)]
+pub struct Element {
+    instruction: i64,
+    index: usize,
+}
+
+const DECRYPTION_KEY: i64 = 811589153;
+const MIX_COUNT: usize = 10;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Element>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_encrypted_file(input)
+    }
+
+    fn part1(encrypted_file: &Self::Input) -> impl Display {
+        MaybeAnswer(decrypt(encrypted_file, 1, 1))
+    }
+
+    fn part2(encrypted_file: &Self::Input) -> impl Display {
+        MaybeAnswer(decrypt(encrypted_file, DECRYPTION_KEY, MIX_COUNT))
+    }
+}
+
+/// Mixes the numbers and sums the coordinates, which are only found from a 0 and while they fit
+/// in 64 bits.
+fn decrypt(encrypted_file: &[Element], decryption_key: i64, mix_count: usize) -> Option<i64> {
+    let mut encrypted_file: Vec<Element> = encrypted_file
+        .iter()
+        .map(|element| {
+            Some(Element {
+                instruction: element.instruction.checked_mul(decryption_key)?,
+                index: element.index,
+            })
+        })
+        .collect::<Option<Vec<Element>>>()?;
+
+    // A lone number has nowhere to move.
+    let mix_count = if encrypted_file.len() < 2 {
+        0
+    } else {
+        mix_count
+    };
+    for _ in 0..mix_count {
+        for index in 0..encrypted_file.len() {
+            execute_instruction(&mut encrypted_file, index);
+        }
+    }
+
+    find_coordinates(&encrypted_file)
+}
+
+fn parse_encrypted_file(input: &str) -> Result<Vec<Element>, ParseError> {
+    parse::lines(input)
+        .enumerate()
+        .map(|(index, line)| {
+            Ok(Element {
+                instruction: line.parse::<i64>(line.text, "a number")?,
+                index,
+            })
+        })
+        .collect()
+}
+
+fn execute_instruction(encrypted_file: &mut Vec<Element>, index: usize) {
+    let initial_position = encrypted_file
+        .iter()
+        .position(|element| element.index == index)
+        .unwrap();
+
+    let removed_element = encrypted_file.remove(initial_position);
+
+    let moved_steps = removed_element
+        .instruction
+        .rem_euclid(encrypted_file.len() as i64);
+    let next_position =
+        (initial_position as i64 + moved_steps).rem_euclid(encrypted_file.len() as i64) as usize;
+
+    if next_position == 0 && removed_element.instruction < 0 {
+        encrypted_file.push(removed_element);
+    } else {
+        encrypted_file.insert(next_position, removed_element);
+    }
+}
+
+fn find_coordinates(encrypted_file: &[Element]) -> Option<i64> {
+    let zero_position = encrypted_file
+        .iter()
+        .position(|element| element.instruction == 0)?;
+
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| encrypted_file[(zero_position + offset) % encrypted_file.len()].instruction)
+        .try_fold(0i64, |total, coordinate| total.checked_add(coordinate))
+}
@@ -0,0 +1,236 @@
+use aoc_core::{parse, ParseError, Pattern, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum Operator {
+    Add,
+    Minus,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug)]
+pub enum Monkey {
+    Value(i64),
+    Operation(String, String, Operator),
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> impl Display {
+        compute_monkey("root", monkeys)
+    }
+
+    fn part2(monkeys: &Self::Input) -> impl Display {
+        let humn_lignee = get_humn_lignee("root", monkeys);
+
+        let Monkey::Operation(monkey1, monkey2, _) = &monkeys["root"] else {
+            unreachable!("root is checked to be an operation when parsing");
+        };
+        if humn_lignee.contains(monkey1) {
+            compute_expected(
+                compute_monkey(monkey2, monkeys),
+                monkey1,
+                monkeys,
+                &humn_lignee,
+            )
+        } else {
+            compute_expected(
+                compute_monkey(monkey1, monkeys),
+                monkey2,
+                monkeys,
+                &humn_lignee,
+            )
+        }
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<HashMap<String, Monkey>, ParseError> {
+    let value_pattern = Pattern::new("{name}: {i64}").unwrap();
+    let operation_pattern = Pattern::new("{name}: {monkey} {operator} {monkey}").unwrap();
+
+    let mut monkeys = HashMap::new();
+    let mut operands = Vec::new();
+    let mut root_line = None;
+
+    for line in parse::lines(input) {
+        if line.text.starts_with("root: ") {
+            root_line = Some(line);
+        }
+        if value_pattern.is_match(line.text) {
+            let (name, value): (String, i64) = line.scan(&value_pattern)?;
+            monkeys.insert(name, Monkey::Value(value));
+        } else if operation_pattern.is_match(line.text) {
+            let (name, operand_1, operator, operand_2): (String, &str, &str, &str) =
+                line.scan(&operation_pattern)?;
+            monkeys.insert(
+                name,
+                Monkey::Operation(
+                    String::from(operand_1),
+                    String::from(operand_2),
+                    match operator {
+                        "+" => Operator::Add,
+                        "-" => Operator::Minus,
+                        "*" => Operator::Multiply,
+                        "/" => Operator::Divide,
+                        _ => return Err(line.error_at(operator, "`+`, `-`, `*` or `/`")),
+                    },
+                ),
+            );
+            operands.push((line, operand_1));
+            operands.push((line, operand_2));
+        } else {
+            return Err(line.error("a monkey like `root: pppw + sjmn` or `dbpl: 5`"));
+        }
+    }
+
+    // Operands are checked once every monkey is known, as they may be defined on later lines.
+    if let Some((line, operand)) = operands
+        .into_iter()
+        .find(|(_, operand)| !monkeys.contains_key(*operand))
+    {
+        return Err(line.error_at(operand, "the name of a monkey"));
+    }
+    // Root compares the numbers of two monkeys, one of them depending on the number of humn.
+    match (root_line, monkeys.get("root")) {
+        (Some(line), Some(Monkey::Value(_))) => {
+            return Err(line.error("a `root` monkey like `root: pppw + sjmn`"))
+        }
+        (_, None) => return Err(ParseError::at_end(input, "the `root` monkey")),
+        _ => {}
+    }
+    if get_humn_lignee("root", &monkeys).is_empty() {
+        return Err(ParseError::at_end(input, "a `humn` monkey below `root`"));
+    }
+
+    Ok(monkeys)
+}
+
+fn compute_monkey(monkey_name: &str, monkeys: &HashMap<String, Monkey>) -> i64 {
+    let monkey = monkeys.get(monkey_name).unwrap();
+
+    match monkey {
+        Monkey::Value(value) => *value,
+        Monkey::Operation(monkey1, monkey2, Operator::Add) => {
+            compute_monkey(monkey1, monkeys) + compute_monkey(monkey2, monkeys)
+        }
+        Monkey::Operation(monkey1, monkey2, Operator::Minus) => {
+            compute_monkey(monkey1, monkeys) - compute_monkey(monkey2, monkeys)
+        }
+        Monkey::Operation(monkey1, monkey2, Operator::Multiply) => {
+            compute_monkey(monkey1, monkeys) * compute_monkey(monkey2, monkeys)
+        }
+        Monkey::Operation(monkey1, monkey2, Operator::Divide) => {
+            compute_monkey(monkey1, monkeys) / compute_monkey(monkey2, monkeys)
+        }
+    }
+}
+
+fn get_humn_lignee(monkey_name: &str, monkeys: &HashMap<String, Monkey>) -> HashSet<String> {
+    if monkey_name == "humn" {
+        return HashSet::from([String::from("humn")]);
+    }
+
+    let monkey = monkeys.get(monkey_name).unwrap();
+
+    match monkey {
+        Monkey::Value(_) => HashSet::new(),
+        Monkey::Operation(monkey1, monkey2, _) => {
+            let mut humn_lignee_1 = get_humn_lignee(monkey1, monkeys);
+            let mut humn_lignee_2 = get_humn_lignee(monkey2, monkeys);
+
+            if !humn_lignee_1.is_empty() {
+                humn_lignee_1.insert(String::from(monkey_name));
+                humn_lignee_1
+            } else if !humn_lignee_2.is_empty() {
+                humn_lignee_2.insert(String::from(monkey_name));
+                humn_lignee_2
+            } else {
+                HashSet::new()
+            }
+        }
+    }
+}
+
+fn compute_expected(
+    expected_value: i64,
+    monkey_name: &str,
+    monkeys: &HashMap<String, Monkey>,
+    humn_lignee: &HashSet<String>,
+) -> i64 {
+    if monkey_name == "humn" {
+        return expected_value;
+    };
+
+    let monkey = monkeys.get(monkey_name).unwrap();
+
+    match monkey {
+        Monkey::Value(_) => unreachable!("only humn yells a value below root on its lignee"),
+        Monkey::Operation(monkey1, monkey2, operator) => {
+            if humn_lignee.contains(monkey1) {
+                match operator {
+                    Operator::Add => compute_expected(
+                        expected_value - compute_monkey(monkey2, monkeys),
+                        monkey1,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                    Operator::Minus => compute_expected(
+                        expected_value + compute_monkey(monkey2, monkeys),
+                        monkey1,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                    Operator::Multiply => compute_expected(
+                        expected_value / compute_monkey(monkey2, monkeys),
+                        monkey1,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                    Operator::Divide => compute_expected(
+                        expected_value * compute_monkey(monkey2, monkeys),
+                        monkey1,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                }
+            } else {
+                match operator {
+                    Operator::Add => compute_expected(
+                        expected_value - compute_monkey(monkey1, monkeys),
+                        monkey2,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                    Operator::Minus => compute_expected(
+                        compute_monkey(monkey1, monkeys) - expected_value,
+                        monkey2,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                    Operator::Multiply => compute_expected(
+                        expected_value / compute_monkey(monkey1, monkeys),
+                        monkey2,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                    Operator::Divide => compute_expected(
+                        compute_monkey(monkey1, monkeys) / expected_value,
+                        monkey2,
+                        monkeys,
+                        humn_lignee,
+                    ),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use aoc_core::{parse, Dir4, Grid, ParseError, Point3, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+/// Position on the board, `(x, y)` from 0 at its top left corner.
+type Position = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    /// Outside of the map.
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Turn {
+    None,
+    Clockwise,
+    CounterClockwise,
+}
+
+#[derive(Debug)]
+pub struct Path {
+    turn: Turn,
+    length: u64,
+}
+
+type Board = Grid<Tile>;
+
+/// 3D vector, used to fold the board into a cube.
+type Vector = Point3<i64>;
+
+/// Orientation of a cube face: its outward normal and the 3D vectors of the
+/// board right and down directions.
+#[derive(Debug, Clone)]
+struct Face {
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Board, Vec<Path>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((board, paths): &Self::Input) -> impl Display {
+        follow_paths(board, paths, |position, direction| {
+            move_board(position, direction, board).map(|position| (position, *direction))
+        })
+    }
+
+    fn part2((board, paths): &Self::Input) -> impl Display {
+        let tile_count = board.iter().filter(|(_, tile)| is_on_map(tile)).count();
+        let size = ((tile_count / 6) as f64).sqrt() as usize;
+        let faces = fold_cube(board, size);
+
+        follow_paths(board, paths, |position, direction| {
+            move_cube(position, direction, board, &faces, size)
+        })
+    }
+}
+
+fn follow_paths<F>(board: &Board, paths: &[Path], mut move_once: F) -> usize
+where
+    F: FnMut(&Position, &Dir4) -> Option<(Position, Dir4)>,
+{
+    let mut position = (board.row(0).iter().position(is_on_map).unwrap(), 0);
+    let mut direction = Dir4::Right;
+
+    for path in paths {
+        direction = match path.turn {
+            Turn::None => direction,
+            Turn::Clockwise => direction.clockwise(),
+            Turn::CounterClockwise => direction.counter_clockwise(),
+        };
+
+        for _ in 0..path.length {
+            if let Some((next_position, next_direction)) = move_once(&position, &direction) {
+                position = next_position;
+                direction = next_direction;
+            } else {
+                break;
+            }
+        }
+    }
+
+    get_result(&position, &direction)
+}
+
+fn parse_input(input: &str) -> Result<(Board, Vec<Path>), ParseError> {
+    let board_input = input.split_once("\n\n").map_or(input, |(board, _)| board);
+    let board = Grid::parse_padded(
+        board_input,
+        "`#`, `.` or a space",
+        Tile::Void,
+        |char| match char {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Open),
+            ' ' => Some(Tile::Void),
+            _ => None,
+        },
+    )?;
+
+    let path_line = parse::lines(input)
+        .nth(board.height() + 1)
+        .ok_or_else(|| ParseError::at_end(input, "an empty line followed by a path"))?;
+    let paths = parse_path(&path_line)?;
+
+    Ok((board, paths))
+}
+
+fn parse_path(line: &parse::Line) -> Result<Vec<Path>, ParseError> {
+    let mut paths = Vec::new();
+    let mut value_start = 0;
+    let mut turn = Turn::None;
+
+    for (index, char) in line.text.char_indices() {
+        if char == 'R' || char == 'L' {
+            let value = line.parse::<u64>(&line.text[value_start..index], "a number of tiles")?;
+            paths.push(Path {
+                length: value,
+                turn,
+            });
+
+            value_start = index + 1;
+            turn = match char {
+                'R' => Turn::Clockwise,
+                _ => Turn::CounterClockwise,
+            };
+        }
+    }
+
+    let value = line.parse::<u64>(&line.text[value_start..], "a number of tiles")?;
+    paths.push(Path {
+        length: value,
+        turn,
+    });
+
+    Ok(paths)
+}
+
+fn move_board(current_position: &Position, direction: &Dir4, board: &Board) -> Option<Position> {
+    let next_position = match board
+        .offset(*current_position, direction.offset())
+        .filter(|&position| is_on_map(&board[position]))
+    {
+        Some(next_position) => next_position,
+        None => find_gap_position(current_position, direction, board),
+    };
+
+    if board[next_position] == Tile::Wall {
+        None
+    } else {
+        Some(next_position)
+    }
+}
+
+fn find_gap_position(position_before_gap: &Position, direction: &Dir4, board: &Board) -> Position {
+    let (x, y) = *position_before_gap;
+
+    match direction {
+        Dir4::Left => (board.row(y).iter().rposition(is_on_map).unwrap(), y),
+        Dir4::Right => (board.row(y).iter().position(is_on_map).unwrap(), y),
+        Dir4::Up => (x, board.column(x).rposition(is_on_map).unwrap()),
+        Dir4::Down => (x, board.column(x).position(is_on_map).unwrap()),
+    }
+}
+
+fn is_on_map(tile: &Tile) -> bool {
+    *tile != Tile::Void
+}
+
+fn move_cube(
+    current_position: &Position,
+    direction: &Dir4,
+    board: &Board,
+    faces: &HashMap<(usize, usize), Face>,
+    size: usize,
+) -> Option<(Position, Dir4)> {
+    let (next_position, next_direction) = match board
+        .offset(*current_position, direction.offset())
+        .filter(|&position| is_on_map(&board[position]))
+    {
+        Some(next_position) => (next_position, *direction),
+        None => find_cube_edge_position(current_position, direction, faces, size),
+    };
+
+    if board[next_position] == Tile::Wall {
+        None
+    } else {
+        Some((next_position, next_direction))
+    }
+}
+
+/// Assigns a 3D orientation to every face of the board, by walking the net
+/// from the first face and folding each edge by a quarter turn.
+fn fold_cube(board: &Board, size: usize) -> HashMap<(usize, usize), Face> {
+    let first_position = (board.row(0).iter().position(is_on_map).unwrap(), 0);
+    let first_tile = get_tile(&first_position, size);
+
+    let mut faces = HashMap::from([(
+        first_tile,
+        Face {
+            normal: Vector::new(0, 0, -1),
+            right: Vector::new(1, 0, 0),
+            down: Vector::new(0, 1, 0),
+        },
+    )]);
+    let mut queue = VecDeque::from([first_tile]);
+
+    while let Some((tile_x, tile_y)) = queue.pop_front() {
+        let face = faces.get(&(tile_x, tile_y)).unwrap().clone();
+
+        let neighbors = [
+            (
+                tile_x.checked_sub(1).map(|tile_x| (tile_x, tile_y)),
+                Face {
+                    normal: -face.right,
+                    right: face.normal,
+                    down: face.down,
+                },
+            ),
+            (
+                Some((tile_x + 1, tile_y)),
+                Face {
+                    normal: face.right,
+                    right: -face.normal,
+                    down: face.down,
+                },
+            ),
+            (
+                tile_y.checked_sub(1).map(|tile_y| (tile_x, tile_y)),
+                Face {
+                    normal: -face.down,
+                    right: face.right,
+                    down: face.normal,
+                },
+            ),
+            (
+                Some((tile_x, tile_y + 1)),
+                Face {
+                    normal: face.down,
+                    right: face.right,
+                    down: -face.normal,
+                },
+            ),
+        ];
+
+        for (tile, neighbor_face) in neighbors {
+            let Some(tile) = tile else {
+                continue;
+            };
+            let tile_position = (tile.0 * size, tile.1 * size);
+
+            if board.get(tile_position).is_some_and(is_on_map) && !faces.contains_key(&tile) {
+                faces.insert(tile, neighbor_face);
+                queue.push_back(tile);
+            }
+        }
+    }
+
+    faces
+}
+
+/// Crosses a cube edge, working with doubled 3D coordinates centered on the
+/// cube so that tile centers stay integers.
+fn find_cube_edge_position(
+    position: &Position,
+    direction: &Dir4,
+    faces: &HashMap<(usize, usize), Face>,
+    size: usize,
+) -> (Position, Dir4) {
+    let tile = get_tile(position, size);
+    let face = faces.get(&tile).unwrap();
+    let half_size = size as i64;
+
+    let local_x = (position.0 - tile.0 * size) as i64;
+    let local_y = (position.1 - tile.1 * size) as i64;
+    let point = face.normal * half_size
+        + face.right * (2 * local_x + 1 - half_size)
+        + face.down * (2 * local_y + 1 - half_size);
+
+    let movement = match direction {
+        Dir4::Left => -face.right,
+        Dir4::Right => face.right,
+        Dir4::Up => -face.down,
+        Dir4::Down => face.down,
+    };
+    let next_point = point + movement - face.normal;
+
+    let (next_tile, next_face) = faces
+        .iter()
+        .find(|(_, next_face)| next_face.normal == movement)
+        .unwrap();
+
+    let next_local_x = (next_point.dot(next_face.right) + half_size - 1) / 2;
+    let next_local_y = (next_point.dot(next_face.down) + half_size - 1) / 2;
+    let next_position = (
+        next_tile.0 * size + next_local_x as usize,
+        next_tile.1 * size + next_local_y as usize,
+    );
+
+    let next_movement = -face.normal;
+    let next_direction = if next_movement == next_face.right {
+        Dir4::Right
+    } else if next_movement == -next_face.right {
+        Dir4::Left
+    } else if next_movement == next_face.down {
+        Dir4::Down
+    } else {
+        Dir4::Up
+    };
+
+    (next_position, next_direction)
+}
+
+fn get_tile(position: &Position, size: usize) -> (usize, usize) {
+    (position.0 / size, position.1 / size)
+}
+
+fn get_result(position: &Position, direction: &Dir4) -> usize {
+    let facing = match direction {
+        Dir4::Right => 0,
+        Dir4::Down => 1,
+        Dir4::Left => 2,
+        Dir4::Up => 3,
+    };
+
+    (position.1 + 1) * 1000 + (position.0 + 1) * 4 + facing
+}
@@ -0,0 +1,105 @@
+use aoc_core::automaton::{self, Automaton, Cell, Topology};
+use aoc_core::{Dir4, Dir8, Grid, ParseError, Solution};
+use std::fmt::Display;
+
+const ROUND_COUNT: usize = 10;
+
+/// Directions considered by the elves in the first round. Each round, the first one moves to
+/// the end.
+const PROPOSAL_ORDER: [Dir4; 4] = [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right];
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Whether each tile holds an elf.
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let elves = Grid::parse(input, "`#` or `.`", |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if elves.find(&true).is_none() {
+            return Err(ParseError::at_end(input, "an elf `#`"));
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> impl Display {
+        let mut automaton = Automaton::new(elves.clone(), Topology::Unbounded);
+        automaton.run(&diffusion_rule(), ROUND_COUNT);
+
+        get_empty_tile_count(&automaton)
+    }
+
+    fn part2(elves: &Self::Input) -> impl Display {
+        let mut automaton = Automaton::new(elves.clone(), Topology::Unbounded);
+
+        automaton.run_until_stable(&diffusion_rule())
+    }
+}
+
+/// A tile keeps its elf unless the elf moves away, and gets the elf moving into it. Elves
+/// proposing the same tile do not move, and those proposals come from at most 2 tiles away.
+fn diffusion_rule() -> impl automaton::Rule<bool> {
+    automaton::rule_fn(2, |tile: &Cell<'_, bool>| {
+        if *tile.state() {
+            // The elf stays when another one proposes the same tile.
+            get_proposed_move(tile, (0, 0))
+                .is_none_or(|direction| get_incoming_elf_count(tile, direction.offset()) > 1)
+        } else {
+            get_incoming_elf_count(tile, (0, 0)) == 1
+        }
+    })
+}
+
+/// Direction where the elf at `elf` from the tile proposes to move, if it moves.
+fn get_proposed_move(tile: &Cell<'_, bool>, elf: (isize, isize)) -> Option<Dir4> {
+    let is_elf_towards = |direction: Dir8| {
+        let (dx, dy) = direction.offset();
+        *tile.get((elf.0 + dx, elf.1 + dy))
+    };
+    if !Dir8::ALL.into_iter().any(is_elf_towards) {
+        return None;
+    }
+
+    (0..PROPOSAL_ORDER.len())
+        .map(|index| PROPOSAL_ORDER[(tile.generation() + index) % PROPOSAL_ORDER.len()])
+        .find(|&direction| {
+            let direction = Dir8::from(direction);
+            [
+                direction.counter_clockwise(),
+                direction,
+                direction.clockwise(),
+            ]
+            .into_iter()
+            .all(|side| !is_elf_towards(side))
+        })
+}
+
+/// Count of the elves proposing to move to the tile at `target` from the tile.
+fn get_incoming_elf_count(tile: &Cell<'_, bool>, target: (isize, isize)) -> usize {
+    Dir4::ALL
+        .into_iter()
+        .filter(|direction| {
+            let (dx, dy) = direction.offset();
+            let elf = (target.0 + dx, target.1 + dy);
+            *tile.get(elf) && get_proposed_move(tile, elf) == Some(direction.opposite())
+        })
+        .count()
+}
+
+/// Empty tiles in the smallest rectangle holding every elf.
+fn get_empty_tile_count(automaton: &Automaton<bool>) -> usize {
+    let elves: Vec<_> = automaton.living_cells().collect();
+    let (min_x, max_x) = min_max(elves.iter().map(|elf| elf.x));
+    let (min_y, max_y) = min_max(elves.iter().map(|elf| elf.y));
+
+    ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize - elves.len()
+}
+
+fn min_max(values: impl Iterator<Item = isize> + Clone) -> (isize, isize) {
+    (values.clone().min().unwrap(), values.max().unwrap())
+}
@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_core::run::<day23_2022::Day>(&aoc_core::input!())
+}
@@ -0,0 +1,304 @@
+use aoc_core::simulation::{Simulate, Simulation};
+use aoc_core::{number_theory, parse, search, Dir4, Grid, MaybeAnswer, ParseError, Solution};
+use log::debug;
+use std::fmt::Display;
+
+/// Position in the valley, `(x, y)` from 0 at the top left wall.
+type Position = (usize, usize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd)]
+enum Journey {
+    Go,
+    ComeBack,
+    GoBack,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct PositionWithJourney {
+    position: Position,
+    journey: Journey,
+}
+
+#[derive(Debug, Clone)]
+struct Blizzard {
+    position: Position,
+    direction: Dir4,
+}
+
+pub struct InitialInput {
+    blizzards: Vec<Blizzard>,
+    /// Whether each position of the valley is a wall.
+    walls: Grid<bool>,
+    start_position: Position,
+    end_position: Position,
+}
+
+/// Blizzards of the valley, moving every minute.
+#[derive(Clone)]
+pub struct Valley<'a> {
+    initial_input: &'a InitialInput,
+    blizzards: Vec<Blizzard>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = InitialInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_initial_blizzard(input)
+    }
+
+    fn part1(initial_input: &Self::Input) -> impl Display {
+        MaybeAnswer(get_journey_duration(initial_input, Journey::ComeBack))
+    }
+
+    fn part2(initial_input: &Self::Input) -> impl Display {
+        MaybeAnswer(get_journey_duration(initial_input, Journey::GoBack))
+    }
+}
+
+impl Simulate for Day {
+    type Simulation<'a> = Valley<'a>;
+
+    fn simulate(initial_input: &Self::Input) -> Valley<'_> {
+        Valley {
+            initial_input,
+            blizzards: initial_input.blizzards.clone(),
+        }
+    }
+}
+
+impl Simulation for Valley<'_> {
+    /// Moves the blizzards by one minute. They never stop.
+    fn step(&mut self) -> bool {
+        self.blizzards = get_next_blizzards(&self.blizzards, &self.initial_input.walls);
+        true
+    }
+
+    /// Walls and blizzards as in the puzzle, with the number of blizzards where several meet.
+    fn render(&self) -> Grid<char> {
+        let mut grid = self
+            .initial_input
+            .walls
+            .map(|&is_wall| if is_wall { '#' } else { '.' });
+        for blizzard in &self.blizzards {
+            grid[blizzard.position] = match grid[blizzard.position] {
+                '.' => get_blizzard_char(blizzard.direction),
+                '>' | '<' | '^' | 'v' => '2',
+                count => char::from_digit(count.to_digit(10).unwrap() + 1, 10).unwrap_or('+'),
+            };
+        }
+        grid
+    }
+
+    fn inspect(&self, (x, y): (usize, usize)) -> String {
+        let content = if self.initial_input.walls[(x, y)] {
+            String::from("wall")
+        } else if (x, y) == self.initial_input.start_position {
+            String::from("start")
+        } else if (x, y) == self.initial_input.end_position {
+            String::from("end")
+        } else {
+            let directions: Vec<&str> = self
+                .blizzards
+                .iter()
+                .filter(|blizzard| blizzard.position == (x, y))
+                .map(|blizzard| match blizzard.direction {
+                    Dir4::Up => "up",
+                    Dir4::Down => "down",
+                    Dir4::Left => "left",
+                    Dir4::Right => "right",
+                })
+                .collect();
+            if directions.is_empty() {
+                String::from("clear")
+            } else {
+                format!("blizzards moving {}", directions.join(", "))
+            }
+        };
+        format!("{x},{y}: {content}")
+    }
+}
+
+/// Counts the minutes until the end is reached with the given journey, if the blizzards ever
+/// let it through.
+fn get_journey_duration(initial_input: &InitialInput, final_journey: Journey) -> Option<usize> {
+    let walls = &initial_input.walls;
+    debug!("Valley of {}x{} cells", walls.width(), walls.height());
+
+    let taken_positions_by_minute = get_taken_positions_by_minute(initial_input);
+    let period = taken_positions_by_minute.len();
+
+    let start = PositionWithJourney {
+        position: initial_input.start_position,
+        journey: Journey::Go,
+    };
+    search::bfs(
+        [(start, 0)],
+        |&(position_with_journey, minute)| {
+            let next_minute = (minute + 1) % period;
+            let taken_positions = &taken_positions_by_minute[next_minute];
+
+            get_neighbors(position_with_journey, initial_input)
+                .into_iter()
+                .filter(|neighbor| !taken_positions[neighbor.position])
+                .map(move |neighbor| (neighbor, next_minute))
+        },
+        |(position_with_journey, _)| {
+            position_with_journey.journey == final_journey
+                && position_with_journey.position == initial_input.end_position
+        },
+    )
+    .map(|path| path.cost)
+}
+
+fn parse_initial_blizzard(input: &str) -> Result<InitialInput, ParseError> {
+    let valley = Grid::parse(input, "`#`, `.`, `>`, `<`, `^` or `v`", |char| {
+        matches!(char, '#' | '.' | '>' | '<' | '^' | 'v').then_some(char)
+    })?;
+    if valley.width() < 3 || valley.height() < 3 {
+        return Err(ParseError::at_end(
+            input,
+            "a valley of at least 3x3 positions",
+        ));
+    }
+    let misplaced = valley.iter().find(|&((x, y), &char)| {
+        let is_border_row = y == 0 || y == valley.height() - 1;
+        let is_border = is_border_row || x == 0 || x == valley.width() - 1;
+        is_border && char != '#' && !(is_border_row && char == '.')
+    });
+    if let Some(((x, y), _)) = misplaced {
+        // Every character of the valley is ASCII, so `x` is also a byte index.
+        let line = parse::lines(input).nth(y).unwrap();
+        return Err(line.error_at_char(x, "a wall `#` around the valley"));
+    }
+
+    let blizzards = valley
+        .iter()
+        .filter_map(|(position, char)| {
+            let direction = match char {
+                '>' => Dir4::Right,
+                '<' => Dir4::Left,
+                '^' => Dir4::Up,
+                'v' => Dir4::Down,
+                _ => return None,
+            };
+            Some(Blizzard {
+                position,
+                direction,
+            })
+        })
+        .collect();
+
+    let walls = valley.map(|&char| char == '#');
+    let find_gap = |y: usize| {
+        walls
+            .row(y)
+            .iter()
+            .position(|&is_wall| !is_wall)
+            .filter(|&x| x > 0 && x < walls.width() - 1)
+            .map(|x| (x, y))
+            .ok_or_else(|| ParseError::at_end(input, "a gap in the walls of the valley"))
+    };
+    let start_position = find_gap(0)?;
+    let end_position = find_gap(walls.height() - 1)?;
+
+    Ok(InitialInput {
+        blizzards,
+        walls,
+        start_position,
+        end_position,
+    })
+}
+
+fn get_blizzard_char(direction: Dir4) -> char {
+    match direction {
+        Dir4::Up => '^',
+        Dir4::Down => 'v',
+        Dir4::Left => '<',
+        Dir4::Right => '>',
+    }
+}
+
+/// Moves every blizzard by one position, wrapping around the walls of the valley.
+fn get_next_blizzards(blizzards: &[Blizzard], walls: &Grid<bool>) -> Vec<Blizzard> {
+    let inner_width = walls.width() - 2;
+    let inner_height = walls.height() - 2;
+
+    blizzards
+        .iter()
+        .map(|blizzard| {
+            let (x, y) = blizzard.position;
+
+            let position = match blizzard.direction {
+                Dir4::Up => (x, 1 + (inner_height + y - 1 - 1) % inner_height),
+                Dir4::Down => (x, 1 + (inner_height + y - 1 + 1) % inner_height),
+                Dir4::Left => (1 + (inner_width + x - 1 - 1) % inner_width, y),
+                Dir4::Right => (1 + (inner_width + x - 1 + 1) % inner_width, y),
+            };
+
+            Blizzard {
+                position,
+                direction: blizzard.direction,
+            }
+        })
+        .collect()
+}
+
+/// Positions taken by a blizzard at each minute, until they are back to their initial
+/// positions.
+fn get_taken_positions_by_minute(initial_input: &InitialInput) -> Vec<Grid<bool>> {
+    let walls = &initial_input.walls;
+    let period =
+        number_theory::lcm((walls.width() - 2) as u64, (walls.height() - 2) as u64) as usize;
+
+    let mut blizzards = initial_input.blizzards.clone();
+    let mut taken_positions_by_minute = Vec::with_capacity(period);
+    for _ in 0..period {
+        let mut taken_positions = Grid::new(walls.width(), walls.height(), false);
+        for blizzard in &blizzards {
+            taken_positions[blizzard.position] = true;
+        }
+        taken_positions_by_minute.push(taken_positions);
+        blizzards = get_next_blizzards(&blizzards, walls);
+    }
+    taken_positions_by_minute
+}
+
+/// Positions reachable in one minute, staying in place included.
+fn get_neighbors_position(position: Position, walls: &Grid<bool>) -> Vec<Position> {
+    walls
+        .neighbours4(position)
+        .chain([position])
+        .filter(|&position| !walls[position])
+        .collect()
+}
+
+fn get_neighbors(
+    position_with_journey: PositionWithJourney,
+    initial_input: &InitialInput,
+) -> Vec<PositionWithJourney> {
+    let journey = position_with_journey.journey;
+
+    get_neighbors_position(position_with_journey.position, &initial_input.walls)
+        .into_iter()
+        .map(|position| {
+            if position == initial_input.start_position && journey == Journey::ComeBack {
+                return PositionWithJourney {
+                    position,
+                    journey: Journey::GoBack,
+                };
+            }
+
+            if position == initial_input.end_position && journey == Journey::Go {
+                return PositionWithJourney {
+                    position,
+                    journey: Journey::ComeBack,
+                };
+            }
+
+            PositionWithJourney { position, journey }
+        })
+        .collect()
+}
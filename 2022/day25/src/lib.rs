@@ -0,0 +1,77 @@
+use aoc_core::{parse, MaybeAnswer, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| snafu_to_decimal(&line))
+            .collect()
+    }
+
+    fn part1(fuel_requirements: &Self::Input) -> impl Display {
+        let total_requirement = fuel_requirements
+            .iter()
+            .try_fold(0i64, |total, &requirement| total.checked_add(requirement));
+        MaybeAnswer(total_requirement.map(decimal_to_snafu))
+    }
+
+    fn part2(_fuel_requirements: &Self::Input) -> impl Display {
+        "Merry Christmas!"
+    }
+}
+
+fn snafu_part_to_digit(snafu_part: char) -> Option<i64> {
+    match snafu_part {
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None,
+    }
+}
+
+fn snafu_to_decimal(line: &parse::Line) -> Result<i64, ParseError> {
+    let mut decimal: i64 = 0;
+
+    for (index, snafu_part) in line.text.char_indices() {
+        let digit = snafu_part_to_digit(snafu_part).ok_or_else(|| {
+            line.error_at_char(index, "a SNAFU digit among `=`, `-`, `0`, `1` and `2`")
+        })?;
+        decimal = decimal
+            .checked_mul(5)
+            .and_then(|decimal| decimal.checked_add(digit))
+            .ok_or_else(|| line.error("a SNAFU number fitting in 64 bits"))?;
+    }
+
+    Ok(decimal)
+}
+
+/// SNAFU digit written for each remainder of a division by 5.
+const SNAFU_PARTS: [char; 5] = ['0', '1', '2', '=', '-'];
+
+fn decimal_to_snafu(decimal: i64) -> String {
+    let mut remain = decimal;
+    let mut stafu: Vec<char> = Vec::new();
+
+    loop {
+        let remainder = remain.rem_euclid(5);
+        stafu.push(SNAFU_PARTS[remainder as usize]);
+        remain = remain.div_euclid(5);
+        // Remainders 3 and 4 are written as -2 and -1, carrying one to the next digit.
+        if remainder > 2 {
+            remain += 1;
+        }
+
+        if remain == 0 {
+            break;
+        }
+    }
+
+    stafu.reverse();
+    stafu.into_iter().collect()
+}
@@ -0,0 +1,78 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::Answers;
+
+/// File next to the day inputs holding their expected answers.
+pub const MANIFEST_FILE: &str = "answers.toml";
+
+/// Expected answers of a day, by input file name.
+pub type Manifest = BTreeMap<String, Answers>;
+
+/// Loads the answers manifest of a day directory, if there is one.
+pub fn load_manifest(day_directory: &Path) -> Option<Manifest> {
+    let path = day_directory.join(MANIFEST_FILE);
+    let content = fs::read_to_string(&path).ok()?;
+
+    let manifest = toml::from_str(&content)
+        .unwrap_or_else(|error| panic!("Could not parse {}: {error}", path.display()));
+    Some(manifest)
+}
+
+/// Describes the parts whose answer differs from the expected one, line by line.
+pub fn diff(expected: &Answers, actual: &Answers) -> Option<String> {
+    let parts = [
+        (1, &expected.part1, &actual.part1),
+        (2, &expected.part2, &actual.part2),
+    ];
+
+    let differences: Vec<String> = parts
+        .into_iter()
+        .filter(|(_, expected, actual)| expected != actual)
+        .map(|(part, expected, actual)| {
+            let mut difference = format!("part {part}:");
+            for line in expected.lines() {
+                difference.push_str(&format!("\n  - {line}"));
+            }
+            for line in actual.lines() {
+                difference.push_str(&format!("\n  + {line}"));
+            }
+            difference
+        })
+        .collect();
+
+    (!differences.is_empty()).then(|| differences.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(part1: &str, part2: &str) -> Answers {
+        Answers {
+            part1: String::from(part1),
+            part2: String::from(part2),
+        }
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest: Manifest =
+            toml::from_str("[\"example.txt\"]\npart1 = \"152\"\npart2 = \"\"\"\n#.\n.#\"\"\"\n")
+                .unwrap();
+
+        assert_eq!(manifest["example.txt"], answers("152", "#.\n.#"));
+    }
+
+    #[test]
+    fn test_diff_same_answers() {
+        assert_eq!(diff(&answers("1", "2"), &answers("1", "2")), None);
+    }
+
+    #[test]
+    fn test_diff_changed_answer() {
+        assert_eq!(
+            diff(&answers("1", "#.\n.#"), &answers("1", "##\n.#")),
+            Some(String::from("part 2:\n  - #.\n  - .#\n  + ##\n  + .#"))
+        );
+    }
+}
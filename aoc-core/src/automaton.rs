@@ -0,0 +1,370 @@
+//! Cellular automata: every generation, each cell of a grid takes a state computed by a rule
+//! from the cells around it in the previous generation.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    mem,
+    str::FromStr,
+};
+
+use crate::{Dir8, Grid, Point2};
+
+/// What lies beyond the edges of the initial grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Topology {
+    /// Dead cells, that never change.
+    Finite,
+    /// The other side of the grid: the left edge touches the right one, and the top edge the
+    /// bottom one.
+    Toroidal,
+    /// More cells, dead at first. The grid grows as living cells get close to its edges.
+    Unbounded,
+}
+
+/// Next state of a cell, given the current generation.
+pub trait Rule<T> {
+    /// Distance up to which cells can change the next state of a cell.
+    fn radius(&self) -> usize;
+
+    fn next_state(&self, cell: &Cell<'_, T>) -> T;
+}
+
+/// Life-like rule, for cells alive or dead: a dead cell is born for some counts of living
+/// neighbours, diagonals included, and a living cell survives for some others. Parses from
+/// rule strings such as `B3/S23`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LifeRule {
+    birth: [bool; 9],
+    survival: [bool; 9],
+}
+
+/// Rule string that is not `B` then digits, `/`, `S` then digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRuleError;
+
+/// Rule computed by a closure, reading the cells up to `radius` away.
+#[derive(Debug, Clone, Copy)]
+pub struct FnRule<F> {
+    radius: usize,
+    function: F,
+}
+
+/// Cell whose next state is computed, reading the current generation around it.
+pub struct Cell<'a, T> {
+    automaton: &'a Automaton<T>,
+    position: Point2<isize>,
+}
+
+/// Cells on a dense grid, with the grid of the next generation kept aside to be reused.
+#[derive(Debug, Clone)]
+pub struct Automaton<T> {
+    cells: Grid<T>,
+    next_cells: Grid<T>,
+    /// Position of the top left cell of the grid, moving away as an unbounded grid grows.
+    origin: Point2<isize>,
+    topology: Topology,
+    generation: usize,
+    dead: T,
+}
+
+impl LifeRule {
+    /// Conway's Game of Life, `B3/S23`.
+    pub const CONWAY: LifeRule = LifeRule {
+        birth: [false, false, false, true, false, false, false, false, false],
+        survival: [false, false, true, true, false, false, false, false, false],
+    };
+}
+
+impl Rule<bool> for LifeRule {
+    fn radius(&self) -> usize {
+        1
+    }
+
+    fn next_state(&self, cell: &Cell<'_, bool>) -> bool {
+        let living_neighbour_count = cell.count_neighbours(|&is_alive| is_alive);
+        if *cell.state() {
+            self.survival[living_neighbour_count]
+        } else {
+            self.birth[living_neighbour_count]
+        }
+    }
+}
+
+impl FromStr for LifeRule {
+    type Err = ParseRuleError;
+
+    fn from_str(text: &str) -> Result<LifeRule, ParseRuleError> {
+        let (birth, survival) = text.split_once('/').ok_or(ParseRuleError)?;
+        let counts = |text: &str, prefix: char| {
+            let digits = text.strip_prefix(prefix).ok_or(ParseRuleError)?;
+            let mut counts = [false; 9];
+            for digit in digits.chars() {
+                match digit.to_digit(10) {
+                    Some(count @ 0..=8) => counts[count as usize] = true,
+                    _ => return Err(ParseRuleError),
+                }
+            }
+            Ok(counts)
+        };
+
+        Ok(LifeRule {
+            birth: counts(birth, 'B')?,
+            survival: counts(survival, 'S')?,
+        })
+    }
+}
+
+impl Display for ParseRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a rule string like `B3/S23`")
+    }
+}
+
+impl Error for ParseRuleError {}
+
+pub fn rule_fn<T, F: Fn(&Cell<'_, T>) -> T>(radius: usize, function: F) -> FnRule<F> {
+    FnRule { radius, function }
+}
+
+impl<T, F: Fn(&Cell<'_, T>) -> T> Rule<T> for FnRule<F> {
+    fn radius(&self) -> usize {
+        self.radius
+    }
+
+    fn next_state(&self, cell: &Cell<'_, T>) -> T {
+        (self.function)(cell)
+    }
+}
+
+impl<'a, T: Clone + Default + PartialEq> Cell<'a, T> {
+    /// Position of the cell, from `(0, 0)` at the top left of the initial grid.
+    pub fn position(&self) -> Point2<isize> {
+        self.position
+    }
+
+    /// Number of the current generation, 0 for the initial grid.
+    pub fn generation(&self) -> usize {
+        self.automaton.generation
+    }
+
+    pub fn state(&self) -> &'a T {
+        self.get((0, 0))
+    }
+
+    /// State of the cell moved by `(dx, dy)`, as given by [`Dir8::offset`]. It must not be
+    /// further than the radius of the rule.
+    pub fn get(&self, (dx, dy): (isize, isize)) -> &'a T {
+        self.automaton.get(self.position + Point2::new(dx, dy))
+    }
+
+    /// Count of the 8 neighbours, diagonals included, matching `predicate`.
+    pub fn count_neighbours(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        Dir8::ALL
+            .into_iter()
+            .filter(|direction| predicate(self.get(direction.offset())))
+            .count()
+    }
+}
+
+impl<T: Clone + Default + PartialEq> Automaton<T> {
+    /// Automaton starting from `cells`, the default state being the dead one. A toroidal grid
+    /// cannot be empty, as it has no cell to wrap around to.
+    pub fn new(cells: Grid<T>, topology: Topology) -> Automaton<T> {
+        assert!(
+            topology != Topology::Toroidal || (cells.width() > 0 && cells.height() > 0),
+            "a toroidal grid cannot be empty"
+        );
+        Automaton {
+            next_cells: cells.clone(),
+            cells,
+            origin: Point2::new(0, 0),
+            topology,
+            generation: 0,
+            dead: T::default(),
+        }
+    }
+
+    /// Number of generations computed so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Cells of the current generation. An unbounded grid may have grown from the initial one,
+    /// its top left cell being at [`Automaton::origin`].
+    pub fn cells(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    pub fn origin(&self) -> Point2<isize> {
+        self.origin
+    }
+
+    /// State of the cell at a position, from `(0, 0)` at the top left of the initial grid.
+    pub fn get(&self, position: Point2<isize>) -> &T {
+        let (x, y) = (position.x - self.origin.x, position.y - self.origin.y);
+        let (width, height) = (self.cells.width() as isize, self.cells.height() as isize);
+
+        match self.topology {
+            Topology::Toroidal => {
+                &self.cells[(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)]
+            }
+            Topology::Finite | Topology::Unbounded => {
+                if (0..width).contains(&x) && (0..height).contains(&y) {
+                    &self.cells[(x as usize, y as usize)]
+                } else {
+                    &self.dead
+                }
+            }
+        }
+    }
+
+    /// Positions of the cells that are not dead.
+    pub fn living_cells(&self) -> impl Iterator<Item = Point2<isize>> + '_ {
+        self.cells
+            .iter()
+            .filter(|(_, state)| **state != self.dead)
+            .map(|((x, y), _)| self.origin + Point2::new(x as isize, y as isize))
+    }
+
+    /// Computes the next generation. Returns whether any cell changed.
+    pub fn step(&mut self, rule: &impl Rule<T>) -> bool {
+        if self.topology == Topology::Unbounded {
+            self.grow(rule.radius());
+        }
+
+        let mut next_cells = mem::replace(&mut self.next_cells, Grid::new(0, 0, self.dead.clone()));
+        let mut is_changed = false;
+        for (x, y) in self.cells.positions() {
+            let cell = Cell {
+                automaton: self,
+                position: self.origin + Point2::new(x as isize, y as isize),
+            };
+            let next_state = rule.next_state(&cell);
+            is_changed |= next_state != self.cells[(x, y)];
+            next_cells[(x, y)] = next_state;
+        }
+
+        self.next_cells = mem::replace(&mut self.cells, next_cells);
+        self.generation += 1;
+        is_changed
+    }
+
+    /// Computes `generation_count` more generations.
+    pub fn run(&mut self, rule: &impl Rule<T>, generation_count: usize) {
+        for _ in 0..generation_count {
+            self.step(rule);
+        }
+    }
+
+    /// Computes generations until one changes no cell. Returns the number of that generation.
+    pub fn run_until_stable(&mut self, rule: &impl Rule<T>) -> usize {
+        while self.step(rule) {}
+        self.generation
+    }
+
+    /// Pads the grid with dead cells, so that no living cell is closer than `radius` to an
+    /// edge. Padding a quarter of the size at once keeps growing rare.
+    fn grow(&mut self, radius: usize) {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let is_near_edge = self.cells.iter().any(|((x, y), state)| {
+            *state != self.dead
+                && (x < radius || y < radius || x + radius >= width || y + radius >= height)
+        });
+        if !is_near_edge {
+            return;
+        }
+
+        let padding = radius + width.max(height) / 4;
+        let mut cells = Grid::new(width + 2 * padding, height + 2 * padding, self.dead.clone());
+        for ((x, y), state) in self.cells.iter() {
+            cells[(x + padding, y + padding)] = state.clone();
+        }
+
+        self.origin -= Point2::new(padding as isize, padding as isize);
+        self.next_cells = cells.clone();
+        self.cells = cells;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Grid<bool> {
+        Grid::parse(text, "`#` or `.`", |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_life_rule() {
+        assert_eq!("B3/S23".parse(), Ok(LifeRule::CONWAY));
+        assert_eq!(
+            "B36/S".parse::<LifeRule>().map(|rule| rule.birth[6]),
+            Ok(true)
+        );
+        assert_eq!("B9/S23".parse::<LifeRule>(), Err(ParseRuleError));
+        assert_eq!("S23/B3".parse::<LifeRule>(), Err(ParseRuleError));
+    }
+
+    #[test]
+    fn test_finite_blinker() {
+        let mut automaton = Automaton::new(parse("...\n###\n...\n"), Topology::Finite);
+
+        assert!(automaton.step(&LifeRule::CONWAY));
+        assert_eq!(automaton.cells(), &parse(".#.\n.#.\n.#.\n"));
+        automaton.run(&LifeRule::CONWAY, 3);
+        assert_eq!(automaton.generation(), 4);
+        assert_eq!(automaton.cells(), &parse("...\n###\n...\n"));
+    }
+
+    #[test]
+    fn test_toroidal_glider() {
+        let glider = parse(".#...\n..#..\n###..\n.....\n.....\n");
+        let mut automaton = Automaton::new(glider.clone(), Topology::Toroidal);
+
+        // A glider moves one cell diagonally every 4 generations, around the torus.
+        automaton.run(&LifeRule::CONWAY, 20);
+
+        assert_eq!(automaton.cells(), &glider);
+    }
+
+    #[test]
+    #[should_panic(expected = "a toroidal grid cannot be empty")]
+    fn test_empty_toroidal() {
+        Automaton::new(parse(""), Topology::Toroidal);
+    }
+
+    #[test]
+    fn test_unbounded_glider() {
+        let mut automaton = Automaton::new(parse(".#.\n..#\n###\n"), Topology::Unbounded);
+
+        automaton.run(&LifeRule::CONWAY, 40);
+
+        let mut living_cells: Vec<Point2<isize>> = automaton.living_cells().collect();
+        living_cells.sort_by_key(|position| (position.y, position.x));
+        assert_eq!(
+            living_cells,
+            [(11, 10), (12, 11), (10, 12), (11, 12), (12, 12)].map(|(x, y)| Point2::new(x, y))
+        );
+    }
+
+    #[test]
+    fn test_rule_fn() {
+        // Values spread to the right, one cell every generation.
+        let rule = rule_fn(1, |cell: &Cell<'_, u8>| {
+            *cell.state().max(cell.get((-1, 0)))
+        });
+        let mut automaton = Automaton::new(
+            Grid::from_rows(vec![vec![1, 0, 0]]).unwrap(),
+            Topology::Finite,
+        );
+
+        assert_eq!(automaton.run_until_stable(&rule), 3);
+        assert_eq!(automaton.cells().row(0), [1, 1, 1]);
+    }
+}
@@ -0,0 +1,264 @@
+//! Branch and bound search for the best solution reachable from a state, skipping the states
+//! whose bound cannot beat the best solution found so far.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    hash::Hash,
+};
+
+/// Optimisation puzzle, maximising the value of the solutions. Wrap values in
+/// [`std::cmp::Reverse`] to minimise them instead.
+pub trait Problem {
+    type State: Clone + Eq + Hash;
+    type Move: Clone;
+    type Value: Copy + Ord;
+
+    /// Moves playable from a state, with the state each one leads to. Depth first search
+    /// explores them in order.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::Move, Self::State)>;
+
+    /// Value of stopping at a state, `None` if it is not a solution.
+    fn value(&self, state: &Self::State) -> Option<Self::Value>;
+
+    /// Value that no solution reachable from a state, itself included, can beat.
+    fn bound(&self, state: &Self::State) -> Self::Value;
+}
+
+/// Best solution found, with the moves reaching it from the initial state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optimum<M, V> {
+    pub value: V,
+    pub moves: Vec<M>,
+}
+
+/// Explored state, with the index of the state it was reached from.
+struct Node<S, M> {
+    state: S,
+    parent: Option<(usize, M)>,
+}
+
+/// Best solution, exploring the most recent states first. Little memory besides the table of
+/// explored states is needed, and good solutions are found early when `successors` lists the
+/// most promising moves first.
+pub fn depth_first<P: Problem>(
+    problem: &P,
+    initial: P::State,
+) -> Option<Optimum<P::Move, P::Value>> {
+    let mut best: Option<Optimum<P::Move, P::Value>> = None;
+    let mut explored: HashSet<P::State> = HashSet::new();
+    let mut moves: Vec<P::Move> = Vec::new();
+    let mut stack = vec![(problem.bound(&initial), 0, None, initial)];
+
+    while let Some((bound, depth, last_move, state)) = stack.pop() {
+        if !can_improve(&best, bound) || !explored.insert(state.clone()) {
+            continue;
+        }
+        moves.truncate(depth);
+        moves.extend(last_move);
+
+        if let Some(value) = problem.value(&state) {
+            if best.as_ref().is_none_or(|best| value > best.value) {
+                best = Some(Optimum {
+                    value,
+                    moves: moves.clone(),
+                });
+            }
+        }
+
+        let successors = problem.successors(&state);
+        for (next_move, next_state) in successors.into_iter().rev() {
+            let bound = problem.bound(&next_state);
+            if can_improve(&best, bound) {
+                stack.push((bound, moves.len(), Some(next_move), next_state));
+            }
+        }
+    }
+
+    best
+}
+
+/// Best solution, exploring first the states with the best bound. It stops as soon as no
+/// state left can beat the best solution, at the cost of keeping every state queued.
+pub fn best_first<P: Problem>(
+    problem: &P,
+    initial: P::State,
+) -> Option<Optimum<P::Move, P::Value>> {
+    let mut best: Option<(P::Value, usize)> = None;
+    let mut explored: HashSet<P::State> = HashSet::new();
+    let mut nodes: Vec<Node<P::State, P::Move>> = Vec::new();
+    // Ties are broken by the first queued state.
+    let mut heap = BinaryHeap::from([(problem.bound(&initial), Reverse(0))]);
+    nodes.push(Node {
+        state: initial,
+        parent: None,
+    });
+
+    while let Some((bound, Reverse(index))) = heap.pop() {
+        if best.is_some_and(|(value, _)| bound <= value) {
+            break;
+        }
+        if !explored.insert(nodes[index].state.clone()) {
+            continue;
+        }
+
+        if let Some(value) = problem.value(&nodes[index].state) {
+            if best.is_none_or(|(best_value, _)| value > best_value) {
+                best = Some((value, index));
+            }
+        }
+
+        for (next_move, next_state) in problem.successors(&nodes[index].state) {
+            let bound = problem.bound(&next_state);
+            if best.is_none_or(|(value, _)| bound > value) {
+                heap.push((bound, Reverse(nodes.len())));
+                nodes.push(Node {
+                    state: next_state,
+                    parent: Some((index, next_move)),
+                });
+            }
+        }
+    }
+
+    best.map(|(value, index)| Optimum {
+        value,
+        moves: reconstruct_moves(nodes, index),
+    })
+}
+
+fn can_improve<M, V: Ord>(best: &Option<Optimum<M, V>>, bound: V) -> bool {
+    best.as_ref().is_none_or(|best| bound > best.value)
+}
+
+fn reconstruct_moves<S, M>(mut nodes: Vec<Node<S, M>>, mut index: usize) -> Vec<M> {
+    let mut moves = Vec::new();
+    while let Some((parent, last_move)) = nodes[index].parent.take() {
+        moves.push(last_move);
+        index = parent;
+    }
+    moves.reverse();
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0-1 knapsack: items are taken or left in order, the state being the index of the next
+    /// item with the weight and value taken so far.
+    struct Knapsack {
+        items: Vec<(u32, u32)>,
+        capacity: u32,
+    }
+
+    impl Problem for Knapsack {
+        type State = (usize, u32, u32);
+        type Move = bool;
+        type Value = u32;
+
+        fn successors(&self, &(index, weight, value): &Self::State) -> Vec<(bool, Self::State)> {
+            let Some(&(item_weight, item_value)) = self.items.get(index) else {
+                return Vec::new();
+            };
+
+            let mut successors = Vec::new();
+            if weight + item_weight <= self.capacity {
+                successors.push((true, (index + 1, weight + item_weight, value + item_value)));
+            }
+            successors.push((false, (index + 1, weight, value)));
+            successors
+        }
+
+        fn value(&self, &(index, _, value): &Self::State) -> Option<u32> {
+            (index == self.items.len()).then_some(value)
+        }
+
+        fn bound(&self, &(index, _, value): &Self::State) -> u32 {
+            value
+                + self.items[index..]
+                    .iter()
+                    .map(|&(_, value)| value)
+                    .sum::<u32>()
+        }
+    }
+
+    fn knapsack() -> Knapsack {
+        Knapsack {
+            items: vec![(5, 10), (4, 40), (6, 30), (3, 50)],
+            capacity: 10,
+        }
+    }
+
+    #[test]
+    fn test_depth_first() {
+        let optimum = depth_first(&knapsack(), (0, 0, 0)).unwrap();
+
+        assert_eq!(optimum.value, 90);
+        assert_eq!(optimum.moves, vec![false, true, false, true]);
+    }
+
+    #[test]
+    fn test_best_first() {
+        let optimum = best_first(&knapsack(), (0, 0, 0)).unwrap();
+
+        assert_eq!(optimum.value, 90);
+        assert_eq!(optimum.moves, vec![false, true, false, true]);
+
+        let empty = Knapsack {
+            items: vec![(5, 10)],
+            capacity: 0,
+        };
+        assert_eq!(
+            best_first(&empty, (0, 0, 0)),
+            Some(Optimum {
+                value: 0,
+                moves: vec![false]
+            })
+        );
+    }
+
+    /// Fewest coins adding up to a total, the state being the sum and the count of the coins.
+    struct Change {
+        coins: Vec<u32>,
+        total: u32,
+    }
+
+    impl Problem for Change {
+        type State = (u32, u32);
+        type Move = u32;
+        type Value = Reverse<u32>;
+
+        fn successors(&self, &(sum, count): &Self::State) -> Vec<(u32, Self::State)> {
+            self.coins
+                .iter()
+                .filter(|&&coin| sum + coin <= self.total)
+                .map(|&coin| (coin, (sum + coin, count + 1)))
+                .collect()
+        }
+
+        fn value(&self, &(sum, count): &Self::State) -> Option<Reverse<u32>> {
+            (sum == self.total).then_some(Reverse(count))
+        }
+
+        fn bound(&self, &(sum, count): &Self::State) -> Reverse<u32> {
+            let largest_coin = self.coins.iter().max().unwrap();
+            Reverse(count + (self.total - sum).div_ceil(*largest_coin))
+        }
+    }
+
+    #[test]
+    fn test_minimising() {
+        let change = Change {
+            coins: vec![1, 5, 6],
+            total: 10,
+        };
+
+        for optimum in [
+            depth_first(&change, (0, 0)).unwrap(),
+            best_first(&change, (0, 0)).unwrap(),
+        ] {
+            assert_eq!(optimum.value, Reverse(2));
+            assert_eq!(optimum.moves, vec![5, 5]);
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Lazy enumerators of compositions, subsets and permutations, with counting functions that
+//! do not enumerate.
+
+use crate::point::Coordinate;
+use std::collections::BTreeMap;
+
+/// Ways to write `total` as an ordered sum of `parts` values, each between `min` and `max`,
+/// in lexicographic order.
+#[derive(Debug, Clone)]
+pub struct Compositions {
+    min: u32,
+    max: u32,
+    next: Option<Vec<u32>>,
+}
+
+/// Subsets of `k` items, as items in their original order, in lexicographic order of their
+/// indexes.
+#[derive(Debug, Clone)]
+pub struct Subsets<'a, T> {
+    items: &'a [T],
+    indexes: Option<Vec<usize>>,
+}
+
+/// Subsets of non-negative items adding up to a target, in lexicographic order of their
+/// indexes. Equal items at different indexes make different subsets.
+#[derive(Debug, Clone)]
+pub struct SubsetsWithSum<'a, T> {
+    items: &'a [T],
+    target: T,
+    /// Indexes of the current subset, and their sum.
+    chosen: Vec<usize>,
+    sum: T,
+    next_index: usize,
+    is_started: bool,
+}
+
+/// Orderings of a multiset, each one once however many times an item is repeated, in
+/// lexicographic order.
+#[derive(Debug, Clone)]
+pub struct DistinctPermutations<T> {
+    next: Option<Vec<T>>,
+}
+
+pub fn compositions(total: u32, parts: usize) -> Compositions {
+    bounded_compositions(total, parts, 0, total)
+}
+
+pub fn bounded_compositions(total: u32, parts: usize, min: u32, max: u32) -> Compositions {
+    let mut first = vec![min; parts];
+    let is_possible = fill_suffix(&mut first, 0, total, min, max);
+
+    Compositions {
+        min,
+        max,
+        next: is_possible.then_some(first),
+    }
+}
+
+pub fn subsets<T>(items: &[T], k: usize) -> Subsets<'_, T> {
+    Subsets {
+        items,
+        indexes: (k <= items.len()).then(|| (0..k).collect()),
+    }
+}
+
+pub fn subsets_with_sum<T: Coordinate>(items: &[T], target: T) -> SubsetsWithSum<'_, T> {
+    SubsetsWithSum {
+        items,
+        target,
+        chosen: Vec::new(),
+        sum: T::ZERO,
+        next_index: 0,
+        is_started: false,
+    }
+}
+
+pub fn distinct_permutations<T: Ord>(
+    items: impl IntoIterator<Item = T>,
+) -> DistinctPermutations<T> {
+    let mut first: Vec<T> = items.into_iter().collect();
+    first.sort();
+
+    DistinctPermutations { next: Some(first) }
+}
+
+/// Binomial coefficient, `n` choose `k`.
+pub fn binomial(n: u64, k: u64) -> u128 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1, |count, index| {
+        count * (n - index) as u128 / (index + 1) as u128
+    })
+}
+
+/// Number of items of [`bounded_compositions`].
+pub fn count_compositions(total: u32, parts: usize, min: u32, max: u32) -> u128 {
+    // Ways to reach each sum with the parts seen so far.
+    let mut counts = vec![0_u128; total as usize + 1];
+    counts[0] = 1;
+
+    for _ in 0..parts {
+        let mut next_counts = vec![0; counts.len()];
+        for (sum, &count) in counts.iter().enumerate().filter(|(_, &count)| count > 0) {
+            for value in min..=max {
+                let Some(next_count) = next_counts.get_mut(sum + value as usize) else {
+                    break;
+                };
+                *next_count += count;
+            }
+        }
+        counts = next_counts;
+    }
+
+    counts[total as usize]
+}
+
+/// Number of items of [`subsets_with_sum`].
+pub fn count_subsets_with_sum<T: Coordinate>(items: &[T], target: T) -> u128 {
+    // Ways to reach each sum up to the target with the items seen so far.
+    let mut counts = BTreeMap::from([(T::ZERO, 1_u128)]);
+
+    for &item in items {
+        let previous_counts: Vec<(T, u128)> =
+            counts.iter().map(|(&sum, &count)| (sum, count)).collect();
+        for (sum, count) in previous_counts {
+            if let Some(next_sum) = sum.checked_add(item).filter(|&next_sum| next_sum <= target) {
+                *counts.entry(next_sum).or_insert(0) += count;
+            }
+        }
+    }
+
+    counts.get(&target).copied().unwrap_or(0)
+}
+
+/// Number of items of [`distinct_permutations`], given how many times each item repeats.
+pub fn count_distinct_permutations(multiplicities: &[u64]) -> u128 {
+    let mut total = 0;
+    multiplicities.iter().fold(1, |count, &multiplicity| {
+        total += multiplicity;
+        count * binomial(total, multiplicity)
+    })
+}
+
+/// Sets `values[start..]` to the lowest values, in lexicographic order, adding up to `sum`.
+/// Returns whether it is possible.
+fn fill_suffix(values: &mut [u32], start: usize, sum: u32, min: u32, max: u32) -> bool {
+    let length = (values.len() - start) as u64;
+    if (sum as u64) < min as u64 * length || sum as u64 > max as u64 * length {
+        return false;
+    }
+
+    let mut extra = sum - min * length as u32;
+    for value in values[start..].iter_mut().rev() {
+        let added = extra.min(max - min);
+        *value = min + added;
+        extra -= added;
+    }
+    true
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<u32>;
+
+    fn next(&mut self) -> Option<Vec<u32>> {
+        let current = self.next.take()?;
+
+        let mut next = current.clone();
+        let mut suffix_sum = next.last().copied().unwrap_or(0);
+        for index in (0..next.len().saturating_sub(1)).rev() {
+            if next[index] < self.max
+                && suffix_sum > 0
+                && fill_suffix(&mut next, index + 1, suffix_sum - 1, self.min, self.max)
+            {
+                next[index] += 1;
+                self.next = Some(next);
+                break;
+            }
+            suffix_sum += next[index];
+        }
+
+        Some(current)
+    }
+}
+
+impl<T: Clone> Iterator for Subsets<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let indexes = self.indexes.as_mut()?;
+        let subset = indexes
+            .iter()
+            .map(|&index| self.items[index].clone())
+            .collect();
+
+        let k = indexes.len();
+        let n = self.items.len();
+        match (0..k)
+            .rev()
+            .find(|&position| indexes[position] < n - k + position)
+        {
+            Some(position) => {
+                indexes[position] += 1;
+                for next_position in position + 1..k {
+                    indexes[next_position] = indexes[next_position - 1] + 1;
+                }
+            }
+            None => self.indexes = None,
+        }
+
+        Some(subset)
+    }
+}
+
+impl<T: Coordinate> Iterator for SubsetsWithSum<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if !self.is_started {
+            self.is_started = true;
+            if self.target == T::ZERO {
+                return Some(Vec::new());
+            }
+        }
+
+        loop {
+            let remaining = self.target - self.sum;
+            match (self.next_index..self.items.len()).find(|&index| self.items[index] <= remaining)
+            {
+                Some(index) => {
+                    self.chosen.push(index);
+                    self.sum = self.sum + self.items[index];
+                    self.next_index = index + 1;
+
+                    if self.sum == self.target {
+                        return Some(self.chosen.iter().map(|&index| self.items[index]).collect());
+                    }
+                }
+                None => {
+                    let index = self.chosen.pop()?;
+                    self.sum = self.sum - self.items[index];
+                    self.next_index = index + 1;
+                }
+            }
+        }
+    }
+}
+
+impl<T: Ord + Clone> Iterator for DistinctPermutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let current = self.next.take()?;
+
+        let mut next = current.clone();
+        if let Some(pivot) = (1..next.len())
+            .rev()
+            .find(|&index| next[index - 1] < next[index])
+        {
+            let successor = (pivot..next.len())
+                .rev()
+                .find(|&index| next[pivot - 1] < next[index])
+                .unwrap();
+            next.swap(pivot - 1, successor);
+            next[pivot..].reverse();
+            self.next = Some(next);
+        }
+
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compositions() {
+        assert_eq!(
+            compositions(2, 2).collect::<Vec<_>>(),
+            vec![vec![0, 2], vec![1, 1], vec![2, 0]]
+        );
+        assert_eq!(
+            bounded_compositions(5, 3, 1, 2).collect::<Vec<_>>(),
+            vec![vec![1, 2, 2], vec![2, 1, 2], vec![2, 2, 1]]
+        );
+        assert_eq!(bounded_compositions(7, 3, 1, 2).count(), 0);
+        assert_eq!(
+            compositions(0, 0).collect::<Vec<_>>(),
+            vec![Vec::<u32>::new()]
+        );
+
+        assert_eq!(
+            compositions(100, 4).count() as u128,
+            count_compositions(100, 4, 0, 100)
+        );
+        assert_eq!(count_compositions(5, 3, 1, 2), 3);
+    }
+
+    #[test]
+    fn test_subsets() {
+        assert_eq!(
+            subsets(&['a', 'b', 'c'], 2).collect::<Vec<_>>(),
+            vec![vec!['a', 'b'], vec!['a', 'c'], vec!['b', 'c']]
+        );
+        assert_eq!(subsets(&[1, 2], 0).count(), 1);
+        assert_eq!(subsets(&[1, 2], 3).count(), 0);
+        assert_eq!(subsets(&[0; 10], 4).count() as u128, binomial(10, 4));
+    }
+
+    #[test]
+    fn test_subsets_with_sum() {
+        let containers = [20_u32, 15, 10, 5, 5];
+
+        assert_eq!(
+            subsets_with_sum(&containers, 25).collect::<Vec<_>>(),
+            vec![vec![20, 5], vec![20, 5], vec![15, 10], vec![15, 5, 5]]
+        );
+        assert_eq!(count_subsets_with_sum(&containers, 25), 4);
+        assert_eq!(count_subsets_with_sum(&[0_u8, 1], 1), 2);
+        assert_eq!(count_subsets_with_sum(&[3_u8], 0), 1);
+        assert_eq!(count_subsets_with_sum(&[u8::MAX, 1], 1), 1);
+        assert_eq!(subsets_with_sum(&[0_u8, 1], 1).count(), 2);
+        assert_eq!(subsets_with_sum(&[3_u8], 0).count(), 1);
+    }
+
+    #[test]
+    fn test_distinct_permutations() {
+        assert_eq!(
+            distinct_permutations("aab".chars())
+                .map(String::from_iter)
+                .collect::<Vec<_>>(),
+            vec!["aab", "aba", "baa"]
+        );
+        assert_eq!(distinct_permutations(0..5).count(), 120);
+        assert_eq!(count_distinct_permutations(&[2, 1]), 3);
+        assert_eq!(count_distinct_permutations(&[1; 5]), 120);
+    }
+}
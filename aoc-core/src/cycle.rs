@@ -0,0 +1,209 @@
+//! Cycle detection in sequences of states, to skip ahead to a step far beyond what can be
+//! simulated.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Steps from `start` on repeat every `length` steps: the state after `start + length` steps
+/// is the same as the state after `start` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Step before the end of the first cycle with the same state as after `step` steps.
+    pub fn equivalent_step(&self, step: u64) -> usize {
+        if step < self.start as u64 {
+            return step as usize;
+        }
+        self.start + ((step - self.start as u64) % self.length as u64) as usize
+    }
+
+    /// Number of whole cycles completed after `step` steps.
+    pub fn count(&self, step: u64) -> u64 {
+        step.saturating_sub(self.start as u64) / self.length as u64
+    }
+}
+
+/// Cycle of the sequence starting from `initial`, with Brent's algorithm. Only a few states
+/// are kept at once, but states must be compared whole. Does not return if the sequence never
+/// repeats.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length, moving the tortoise to the hare every power of two steps.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Find the start, with the hare `length` steps ahead of the tortoise.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Cycle of the sequence starting from `initial`, found by recording the `key` of every state
+/// until one repeats. The key must tell apart states that do not lead to the same sequence.
+/// Does not return if the sequence never repeats.
+pub fn find_cycle<S, K: Eq + Hash>(
+    initial: S,
+    step: impl FnMut(&mut S),
+    key: impl FnMut(&S) -> K,
+) -> Cycle {
+    measure_until_cycle(initial, step, key, |_| 0, u64::MAX)
+        .0
+        .unwrap()
+}
+
+/// `value` of the state after `steps` steps from `initial`, the value changing by the same
+/// amount on every cycle found with the `key` of states, as in [`find_cycle`]. Steps after the
+/// last whole cycle are taken from the first cycle, so `steps` need not end on a cycle.
+pub fn extrapolate<S, K: Eq + Hash>(
+    initial: S,
+    step: impl FnMut(&mut S),
+    key: impl FnMut(&S) -> K,
+    value: impl FnMut(&S) -> i64,
+    steps: u64,
+) -> i64 {
+    let (cycle, values) = measure_until_cycle(initial, step, key, value, steps);
+    let Some(cycle) = cycle else {
+        return values[steps as usize];
+    };
+
+    let increase_by_cycle = values[cycle.start + cycle.length] - values[cycle.start];
+    values[cycle.equivalent_step(steps)] + cycle.count(steps) as i64 * increase_by_cycle
+}
+
+/// Values of the states until one has the key of an earlier one, or until `max_steps` steps.
+fn measure_until_cycle<S, K: Eq + Hash>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> i64,
+    max_steps: u64,
+) -> (Option<Cycle>, Vec<i64>) {
+    let mut steps_by_key: HashMap<K, usize> = HashMap::new();
+    let mut values = Vec::new();
+
+    for index in 0.. {
+        values.push(value(&state));
+        if let Some(start) = steps_by_key.insert(key(&state), index) {
+            let cycle = Cycle {
+                start,
+                length: index - start,
+            };
+            return (Some(cycle), values);
+        }
+        if index as u64 == max_steps {
+            break;
+        }
+        step(&mut state);
+    }
+
+    (None, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+    fn next(&value: &u32) -> u32 {
+        if value == 7 {
+            3
+        } else {
+            value + 1
+        }
+    }
+
+    #[test]
+    fn test_brent() {
+        let cycle = brent(0, next);
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 3,
+                length: 5
+            }
+        );
+        assert_eq!(
+            brent(4, next),
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+        assert_eq!(
+            brent(1, |&value| value),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(0, |value| *value = next(value), |&value| value);
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 3,
+                length: 5
+            }
+        );
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(8), 3);
+        assert_eq!(cycle.equivalent_step(1_000_000_007), 7);
+        assert_eq!(cycle.count(1_000_000_007), 200_000_000);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // A running sum of the sequence, keyed on the current value only.
+        let total = |steps| {
+            extrapolate(
+                (0, 0),
+                |(value, total)| {
+                    *value = next(value);
+                    *total += *value as i64;
+                },
+                |&(value, _)| value,
+                |&(_, total)| total,
+                steps,
+            )
+        };
+        let simulated = |steps| {
+            let mut value = 0;
+            (0..steps).fold(0, |total, _| {
+                value = next(&value);
+                total + value as i64
+            })
+        };
+
+        for steps in [0, 2, 3, 8, 9, 10, 41, 1000] {
+            assert_eq!(total(steps), simulated(steps));
+        }
+    }
+}
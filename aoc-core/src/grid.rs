@@ -0,0 +1,321 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse, Dir4, Dir8, ParseError};
+
+/// Dense rectangular grid, stored row by row. Positions are `(x, y)` pairs, `x` being the
+/// column and `y` the row, both from 0 at the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid of the given rows, or `None` when they do not all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one cell per character with `mapper`, which returns `None` for characters
+    /// that are not `expected`. Every line must have the same length.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mapper: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let lines: Vec<parse::Line> = parse::lines(input).collect();
+        let width = lines.first().map_or(0, |line| line.text.chars().count());
+
+        if let Some(line) = lines.iter().find(|line| line.text.chars().count() != width) {
+            return Err(line.error(format!("a line of {width} characters")));
+        }
+        Grid::parse_lines(&lines, width, expected, mapper, || unreachable!())
+    }
+
+    /// Like [`Grid::parse`], but lines shorter than the longest one are padded with
+    /// `padding`, for maps with ragged edges.
+    pub fn parse_padded(
+        input: &str,
+        expected: &str,
+        padding: T,
+        mapper: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError>
+    where
+        T: Clone,
+    {
+        let lines: Vec<parse::Line> = parse::lines(input).collect();
+        let width = lines
+            .iter()
+            .map(|line| line.text.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        Grid::parse_lines(&lines, width, expected, mapper, || padding.clone())
+    }
+
+    fn parse_lines(
+        lines: &[parse::Line],
+        width: usize,
+        expected: &str,
+        mut mapper: impl FnMut(char) -> Option<T>,
+        mut padding: impl FnMut() -> T,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for line in lines {
+            for (index, char) in line.text.char_indices() {
+                cells.push(mapper(char).ok_or_else(|| line.error_at_char(index, expected))?);
+            }
+            for _ in line.text.chars().count()..width {
+                cells.push(padding());
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Position moved by `(dx, dy)`, when it stays in the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// Orthogonal neighbours of a position that are in the grid, clockwise from the top one.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(position, direction.offset()))
+    }
+
+    /// Neighbours of a position that are in the grid, diagonals included, clockwise from the
+    /// top one.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(position, direction.offset()))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} is out of a {}x{} grid",
+            self.width,
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Cells of a column, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(
+            x < self.width,
+            "column {x} is out of a {}x{} grid",
+            self.width,
+            self.height
+        );
+        // A grid without rows has no cell past the first column either.
+        let cells = self.cells.get(x..).unwrap_or(&[]);
+        cells.iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, mapper: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(mapper).collect(),
+        }
+    }
+
+    fn index(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{position:?} is out of a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is out of a {width}x{height} grid"))
+    }
+}
+
+/// Rows on their own lines, cells written next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let error = Grid::parse("12\n3x\n", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!((error.line_number, error.column), (2, 2));
+        let error = Grid::parse("12\n3\n", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!(error.expected, "a line of 2 characters");
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::parse_padded("  #\n.\n", "a tile", ' ', Some).unwrap();
+
+        assert_eq!(grid.to_string(), "  #\n.  ");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_row_and_column() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).rev().collect::<String>(), "fc");
+    }
+
+    #[test]
+    fn test_column_without_rows() {
+        let grid = Grid::new(3, 0, '.');
+
+        assert_eq!(grid.column(2).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of a 3x2 grid")]
+    fn test_column_out_of_grid() {
+        let _ = grid().column(3);
+    }
+}
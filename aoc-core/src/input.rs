@@ -0,0 +1,196 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{logging, progress, Grid, ParseError};
+
+/// Files looked up next to the day manifest when no input path is given, by order of preference.
+const DEFAULT_INPUT_FILES: [&str; 2] = ["real.txt", "example.txt"];
+
+/// Input argument reading the puzzle input from stdin instead of a file.
+pub const STDIN_ARGUMENT: &str = "-";
+
+/// Option giving the puzzle input inline, such as `--input-string abcdef`.
+pub const INPUT_STRING_OPTION: &str = "--input-string";
+
+/// Puzzle input of a day, read once from disk, stdin or the command line.
+#[derive(Debug)]
+pub struct Input {
+    path: PathBuf,
+    content: String,
+}
+
+/// Loads the input of the calling day crate, see [`Input::load`].
+#[macro_export]
+macro_rules! input {
+    () => {
+        $crate::Input::load(env!("CARGO_MANIFEST_DIR"))
+    };
+}
+
+impl Input {
+    /// Loads the input given on the command line, or the default input of the crate living
+    /// in `manifest_dir`. See [`Input::from_arguments`]. `-v` and `-vv` arguments are taken
+    /// out first, and set the verbosity of the logs, see [`crate::logging`], as is
+    /// `--no-progress`, hiding the bars of [`crate::progress`].
+    pub fn load(manifest_dir: &str) -> Input {
+        let mut arguments: Vec<String> = env::args().skip(1).collect();
+        logging::init(logging::take_verbosity(&mut arguments));
+        progress::take_no_progress(&mut arguments);
+        Input::from_arguments(&arguments, Path::new(manifest_dir))
+    }
+
+    /// Input given by CLI arguments: either `--input-string <content>`, or a path resolved
+    /// by [`Input::resolve`].
+    pub fn from_arguments(arguments: &[String], manifest_dir: &Path) -> Input {
+        match arguments {
+            [option, content] if option == INPUT_STRING_OPTION => Input::from_string(content),
+            [argument] => match argument.strip_prefix(&format!("{INPUT_STRING_OPTION}=")) {
+                Some(content) => Input::from_string(content),
+                None => Input::resolve(Some(argument), manifest_dir),
+            },
+            _ => Input::resolve(None, manifest_dir),
+        }
+    }
+
+    /// Reads stdin for `-`, or the file found by [`resolve_path`] otherwise.
+    pub fn resolve(argument: Option<&str>, manifest_dir: &Path) -> Input {
+        match argument {
+            Some(STDIN_ARGUMENT) => Input::from_stdin(),
+            _ => Input::from_path(resolve_path(argument, manifest_dir)),
+        }
+    }
+
+    pub fn from_path(path: impl AsRef<Path>) -> Input {
+        let path = path.as_ref().to_path_buf();
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("Could not read {}: {error}", path.display()));
+
+        Input { path, content }
+    }
+
+    /// Reads stdin until its end, named `<stdin>` in errors.
+    pub fn from_stdin() -> Input {
+        let mut content = String::new();
+        io::stdin()
+            .read_to_string(&mut content)
+            .unwrap_or_else(|error| panic!("Could not read stdin: {error}"));
+
+        Input {
+            path: PathBuf::from("<stdin>"),
+            content,
+        }
+    }
+
+    /// Input given inline, named `<input-string>` in errors. A missing final newline is
+    /// added, as puzzle inputs end with one.
+    pub fn from_string(content: &str) -> Input {
+        let mut content = String::from(content);
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+
+        Input {
+            path: PathBuf::from("<input-string>"),
+            content,
+        }
+    }
+
+    /// Path of the input file, or `<stdin>` and `<input-string>` for the other sources.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.content
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.content.lines()
+    }
+
+    /// Characters of the input as a grid, whose lines must all have the same length.
+    pub fn grid(&self) -> Result<Grid<char>, ParseError> {
+        Grid::parse(self.as_str(), "a character", Some)
+    }
+}
+
+/// Resolves the input path of a day.
+///
+/// A given argument is looked up from the current directory first, then from the day
+/// directory. Without argument, the first existing file of [`DEFAULT_INPUT_FILES`] in the
+/// day directory is used.
+pub fn resolve_path(argument: Option<&str>, manifest_dir: &Path) -> PathBuf {
+    if let Some(argument) = argument {
+        let path = PathBuf::from(argument);
+        if path.is_absolute() || path.exists() {
+            return path;
+        }
+        return manifest_dir.join(path);
+    }
+
+    DEFAULT_INPUT_FILES
+        .iter()
+        .map(|file_name| manifest_dir.join(file_name))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| manifest_dir.join(DEFAULT_INPUT_FILES[0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+    #[test]
+    fn test_resolve_argument_from_manifest_dir() {
+        assert_eq!(
+            resolve_path(Some("example.txt"), Path::new("/nowhere")),
+            PathBuf::from("/nowhere/example.txt")
+        );
+    }
+
+    #[test]
+    fn test_resolve_existing_argument() {
+        assert_eq!(
+            resolve_path(Some(MANIFEST_DIR), Path::new("/nowhere")),
+            PathBuf::from(MANIFEST_DIR)
+        );
+    }
+
+    #[test]
+    fn test_resolve_default_without_input_file() {
+        assert_eq!(
+            resolve_path(None, Path::new("/nowhere")),
+            PathBuf::from("/nowhere/real.txt")
+        );
+    }
+
+    #[test]
+    fn test_input_string_arguments() {
+        let arguments = [String::from(INPUT_STRING_OPTION), String::from("abcdef")];
+        let input = Input::from_arguments(&arguments, Path::new("/nowhere"));
+
+        assert_eq!(input.as_str(), "abcdef\n");
+        assert_eq!(input.path(), Path::new("<input-string>"));
+
+        let input = Input::from_arguments(
+            &[String::from("--input-string=1\n2\n")],
+            Path::new("/nowhere"),
+        );
+        assert_eq!(input.as_str(), "1\n2\n");
+    }
+
+    #[test]
+    fn test_lines_and_grid() {
+        let input = Input {
+            path: PathBuf::new(),
+            content: String::from("ab\ncd\n"),
+        };
+
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["ab", "cd"]);
+        assert_eq!(input.grid().unwrap().to_string(), "ab\ncd");
+    }
+}
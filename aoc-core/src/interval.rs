@@ -0,0 +1,289 @@
+use std::{
+    cmp::{max, min},
+    fmt::Debug,
+};
+
+use crate::point::Coordinate;
+
+/// Integers from `start` to `end`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/// Union of intervals, kept as a sorted list of disjoint intervals. Intervals that overlap
+/// or touch are merged, so `1..=3` and `4..=6` are stored as `1..=6`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Coordinate + Debug> Interval<T> {
+    /// Interval from `start` to `end`, which cannot be before `start`.
+    pub fn new(start: T, end: T) -> Interval<T> {
+        assert!(start <= end, "interval from {start:?} to {end:?} is empty");
+        Interval { start, end }
+    }
+
+    /// Count of integers in the interval. It overflows when the interval covers every value of
+    /// `T`, or most of them for a signed `T`: see [`Interval::checked_len`].
+    pub fn len(&self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    /// Count of integers in the interval, or `None` when it does not fit in `T`.
+    pub fn checked_len(&self) -> Option<T> {
+        self.end.checked_sub(self.start)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn intersects(&self, other: &Interval<T>) -> bool {
+        max(self.start, other.start) <= min(self.end, other.end)
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.intersects(other)
+            .then(|| Interval::new(max(self.start, other.start), min(self.end, other.end)))
+    }
+
+    /// Whether both intervals overlap or follow each other without a gap.
+    fn touches(&self, other: &Interval<T>) -> bool {
+        is_before_or_next(other.start, self.end) && is_before_or_next(self.start, other.end)
+    }
+}
+
+/// Whether `value` is at most `bound + 1`, without overflowing: `value - 1` is only computed
+/// when `value` is above `bound`, so above the minimum of `T`.
+fn is_before_or_next<T: Coordinate>(value: T, bound: T) -> bool {
+    value <= bound || value - T::ONE <= bound
+}
+
+impl<T: Coordinate + Debug> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Disjoint intervals of the set, sorted.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Count of integers in the set. It overflows like [`Interval::len`]: see
+    /// [`IntervalSet::checked_len`].
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, interval| total + interval.len())
+    }
+
+    /// Count of integers in the set, or `None` when it does not fit in `T`.
+    pub fn checked_len(&self) -> Option<T> {
+        self.intervals.iter().try_fold(T::ZERO, |total, interval| {
+            total.checked_add(interval.checked_len()?)
+        })
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Adds an interval, merging it with the ones it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        let mut is_inserted = false;
+
+        for &current in &self.intervals {
+            if current.touches(&merged) {
+                merged = Interval::new(
+                    min(current.start, merged.start),
+                    max(current.end, merged.end),
+                );
+            } else if current.end < merged.start {
+                intervals.push(current);
+            } else {
+                if !is_inserted {
+                    intervals.push(merged);
+                    is_inserted = true;
+                }
+                intervals.push(current);
+            }
+        }
+        if !is_inserted {
+            intervals.push(merged);
+        }
+
+        self.intervals = intervals;
+    }
+
+    /// Removes the integers of an interval, splitting the intervals it falls into.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+
+        for &current in &self.intervals {
+            if !current.intersects(&interval) {
+                intervals.push(current);
+                continue;
+            }
+            if current.start < interval.start {
+                intervals.push(Interval::new(current.start, interval.start - T::ONE));
+            }
+            if interval.end < current.end {
+                intervals.push(Interval::new(interval.end + T::ONE, current.end));
+            }
+        }
+
+        self.intervals = intervals;
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut index, mut other_index) = (0, 0);
+
+        while let (Some(interval), Some(other_interval)) =
+            (self.intervals.get(index), other.intervals.get(other_index))
+        {
+            intervals.extend(interval.intersection(other_interval));
+            if interval.end < other_interval.end {
+                index += 1;
+            } else {
+                other_index += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// Integers of `bounds` that are not in the set.
+    pub fn complement(&self, bounds: Interval<T>) -> IntervalSet<T> {
+        let mut complement = IntervalSet {
+            intervals: vec![bounds],
+        };
+        for &interval in &self.intervals {
+            complement.remove(interval);
+        }
+        complement
+    }
+
+    /// Intervals between two consecutive intervals of the set, sorted.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|pair| Interval::new(pair[0].end + T::ONE, pair[1].start - T::ONE))
+    }
+}
+
+impl<T: Coordinate + Debug> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set<T: Coordinate + Debug>(intervals: &[(T, T)]) -> IntervalSet<T> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut intervals = set(&[(10, 12), (1, 3), (6, 7)]);
+        assert_eq!(intervals, set(&[(1, 3), (6, 7), (10, 12)]));
+        assert_eq!(intervals.len(), 8);
+
+        intervals.insert(Interval::new(4, 5));
+        assert_eq!(
+            intervals.intervals(),
+            [Interval::new(1, 7), Interval::new(10, 12)]
+        );
+        intervals.insert(Interval::new(0, 20));
+        assert_eq!(intervals.intervals(), [Interval::new(0, 20)]);
+
+        let mut extremes = set(&[(u8::MAX, u8::MAX)]);
+        extremes.insert(Interval::new(100, 254));
+        assert_eq!(extremes.intervals(), [Interval::new(100, u8::MAX)]);
+
+        let mut far_apart = set(&[(-100i8, -100)]);
+        far_apart.insert(Interval::new(100, 100));
+        assert_eq!(far_apart, set(&[(-100, -100), (100, 100)]));
+        far_apart.insert(Interval::new(i8::MIN, -101));
+        assert_eq!(far_apart, set(&[(i8::MIN, -100), (100, 100)]));
+    }
+
+    #[test]
+    fn test_checked_len() {
+        assert_eq!(Interval::new(-2i8, 3).checked_len(), Some(6));
+        assert_eq!(Interval::new(i32::MIN, i32::MAX).checked_len(), None);
+        assert_eq!(Interval::new(u8::MIN, 254).checked_len(), Some(u8::MAX));
+        assert_eq!(set(&[(-100i8, 0), (10, 40)]).checked_len(), None);
+        assert_eq!(set(&[(0u8, 9), (20, 29)]).checked_len(), Some(20));
+    }
+
+    #[test]
+    #[should_panic(expected = "interval from 5 to 2 is empty")]
+    fn test_empty_interval() {
+        IntervalSet::new().insert(Interval::new(5u32, 2));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut intervals = set(&[(-5, 5), (10, 12)]);
+
+        intervals.remove(Interval::new(0, 10));
+
+        assert_eq!(intervals, set(&[(-5, -1), (11, 12)]));
+        assert!(intervals.contains(-3));
+        assert!(!intervals.contains(0));
+        assert!(!intervals.contains(13));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 4), (8, 10)]);
+        let b = set(&[(3, 9), (20, 21)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 10), (20, 21)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 4), (8, 9)]));
+        assert_eq!(
+            a.complement(Interval::new(-2, 12)),
+            set(&[(-2, -1), (5, 7), (11, 12)])
+        );
+        assert_eq!(b.gaps().collect::<Vec<_>>(), vec![Interval::new(10, 19)]);
+    }
+}
@@ -0,0 +1,26 @@
+pub mod answers;
+pub mod automaton;
+pub mod branch_and_bound;
+pub mod combinatorics;
+pub mod cycle;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod logging;
+pub mod number_theory;
+pub mod parse;
+pub mod pattern;
+pub mod point;
+pub mod progress;
+pub mod render;
+pub mod search;
+pub mod simulation;
+pub mod solution;
+
+pub use grid::Grid;
+pub use input::Input;
+pub use interval::{Interval, IntervalSet};
+pub use parse::ParseError;
+pub use pattern::Pattern;
+pub use point::{Coordinate, Dir4, Dir8, Point2, Point3};
+pub use solution::{run, solve, solve_timed, Answers, MaybeAnswer, Solution, Timings};
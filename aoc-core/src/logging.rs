@@ -0,0 +1,87 @@
+//! Diagnostics of the solutions, written to stderr so that stdout only holds the answers.
+//! Days log through the `log` macros, shown from `debug!` with `-v` and from `trace!` with
+//! `-vv`.
+
+use std::io::{self, Write};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Logger writing every record to stderr, prefixed by its level and its crate.
+struct StderrLogger;
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Level of the logs shown for a verbosity: warnings and errors by default, debug logs
+/// from 1, every log from 2.
+pub fn level_filter(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Writes the logs up to the level of `verbosity` to stderr. Later calls only change the
+/// level.
+pub fn init(verbosity: u8) {
+    // Fails when a logger is already set, by an earlier call or by a test harness.
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level_filter(verbosity));
+}
+
+/// Removes the `-v`, `-vv`... and `--verbose` arguments, returning the verbosity they add up
+/// to.
+pub fn take_verbosity(arguments: &mut Vec<String>) -> u8 {
+    let mut verbosity: u8 = 0;
+    arguments.retain(|argument| {
+        let count = match argument.strip_prefix('-') {
+            Some("-verbose") => 1,
+            Some(flags) if !flags.is_empty() && flags.chars().all(|flag| flag == 'v') => {
+                flags.len()
+            }
+            _ => return true,
+        };
+        verbosity = verbosity.saturating_add(count.min(u8::MAX as usize) as u8);
+        false
+    });
+    verbosity
+}
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            let _ = writeln!(
+                io::stderr().lock(),
+                "[{} {}] {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        let _ = io::stderr().flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_verbosity() {
+        let mut arguments: Vec<String> = ["-vv", "example.txt", "-", "-v", "--verbose", "-x"]
+            .map(String::from)
+            .to_vec();
+
+        assert_eq!(take_verbosity(&mut arguments), 4);
+        assert_eq!(arguments, ["example.txt", "-", "-x"]);
+        assert_eq!(level_filter(0), LevelFilter::Warn);
+        assert_eq!(level_filter(4), LevelFilter::Trace);
+    }
+}
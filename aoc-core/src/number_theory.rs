@@ -0,0 +1,165 @@
+//! Modular arithmetic, greatest common divisors and divisor sieves.
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    x
+}
+
+/// Least common multiple, 0 if either number is 0.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// Greatest common divisor of every number, 0 for none.
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of every number, 1 for none.
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(1, lcm)
+}
+
+/// `base` to the power `exponent`, modulo `modulus`, by repeated squaring.
+pub fn modpow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let (mut base, mut exponent) = (base as u128 % modulus, exponent);
+    let mut result = 1 % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+
+    result as u64
+}
+
+/// Number `x` below `modulus` such that `value * x` is 1 modulo `modulus`, if `value` and
+/// `modulus` are coprime.
+pub fn mod_inverse(value: u64, modulus: u64) -> Option<u64> {
+    let (gcd, coefficient, _) = extended_gcd(value as i128, modulus as i128);
+    (gcd == 1).then(|| coefficient.rem_euclid(modulus as i128) as u64)
+}
+
+/// Chinese remainder theorem: the `(remainder, modulus)` of the numbers that have every given
+/// remainder for its modulus, the modulus being the least common multiple of them all. Moduli
+/// need not be coprime, but `None` is returned if the congruences contradict each other.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, u64)>) -> Option<(u64, u64)> {
+    congruences
+        .into_iter()
+        .try_fold(
+            (0_i128, 1_i128),
+            |(remainder, modulus), (other, other_modulus)| {
+                let other_modulus = other_modulus as i128;
+                let (gcd, coefficient, _) = extended_gcd(modulus, other_modulus);
+                let difference = other as i128 - remainder;
+                if difference % gcd != 0 {
+                    return None;
+                }
+
+                // Add to the remainder the multiple of the modulus that reaches `other`.
+                let step = other_modulus / gcd;
+                let multiple = (difference / gcd % step * coefficient).rem_euclid(step);
+                let combined_modulus = modulus * step;
+                Some((
+                    (remainder + modulus * multiple).rem_euclid(combined_modulus),
+                    combined_modulus,
+                ))
+            },
+        )
+        .map(|(remainder, modulus)| (remainder as u64, modulus as u64))
+}
+
+/// Divisors of every number up to `limit` included, sorted, with none for 0.
+pub fn divisor_sieve(limit: usize) -> Vec<Vec<usize>> {
+    let mut divisors = vec![Vec::new(); limit + 1];
+    for divisor in 1..=limit {
+        for multiple in (divisor..=limit).step_by(divisor) {
+            divisors[multiple].push(divisor);
+        }
+    }
+    divisors
+}
+
+/// Sum of the divisors of every number up to `limit` included, 0 for 0.
+pub fn sigma_sieve(limit: usize) -> Vec<u64> {
+    bounded_sigma_sieve(limit, limit)
+}
+
+/// Like [`sigma_sieve`], only adding a divisor to its multiples up to `max_quotient` times
+/// itself.
+pub fn bounded_sigma_sieve(limit: usize, max_quotient: usize) -> Vec<u64> {
+    let mut sums = vec![0; limit + 1];
+    for divisor in 1..=limit {
+        for multiple in (divisor..=limit).step_by(divisor).take(max_quotient) {
+            sums[multiple] += divisor as u64;
+        }
+    }
+    sums
+}
+
+/// `(gcd, x, y)` such that `a * x + b * y == gcd`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (gcd, x, y) = extended_gcd(b, a % b);
+    (gcd, y, x - a / b * y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all([]), 1);
+    }
+
+    #[test]
+    fn test_modpow_and_inverse() {
+        assert_eq!(modpow(2, 10, 1000), 24);
+        assert_eq!(modpow(5, 0, 1), 0);
+        assert_eq!(modpow(u64::MAX - 1, u64::MAX, u64::MAX), u64::MAX - 1);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 6), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt([(-1, 4), (0, 3)]), Some((3, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_sieves() {
+        let divisors = divisor_sieve(12);
+        assert_eq!(divisors[12], vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors[7], vec![1, 7]);
+
+        let sigmas = sigma_sieve(12);
+        assert_eq!(&sigmas[..7], &[0, 1, 3, 4, 7, 6, 12]);
+        assert_eq!(sigmas[12], divisors[12].iter().sum::<usize>() as u64);
+
+        // 1 and 2 only reach up to twice themselves.
+        assert_eq!(bounded_sigma_sieve(12, 2)[4], 4 + 2);
+    }
+}
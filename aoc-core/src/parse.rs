@@ -0,0 +1,180 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use regex::{Captures, Regex};
+
+/// Malformed input, pointing at the offending part of a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Input file, known once the error reaches the runner.
+    pub file: Option<PathBuf>,
+    /// Line number, from 1.
+    pub line_number: usize,
+    /// Column of the offending part, from 1.
+    pub column: usize,
+    /// Character count of the offending part, underlined by carets.
+    pub length: usize,
+    pub line: String,
+    /// What was expected instead, such as `"a number"`.
+    pub expected: String,
+}
+
+/// Line of an input, building errors pointing into it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// Line number, from 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Numbered lines of an input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// Error about the whole line.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.text, expected)
+    }
+
+    /// Error about `part`, a slice of the line. The whole line is pointed at when `part` is
+    /// not one of its slices.
+    pub fn error_at(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+
+        let (column, length) =
+            if part_start >= start && part_start + part.len() <= start + self.text.len() {
+                let offset = part_start - start;
+                (
+                    self.text[..offset].chars().count() + 1,
+                    part.chars().count(),
+                )
+            } else {
+                (1, self.text.chars().count())
+            };
+
+        ParseError {
+            file: None,
+            line_number: self.number,
+            column,
+            length,
+            line: String::from(self.text),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error about the character starting at the byte `index` of the line.
+    pub fn error_at_char(&self, index: usize, expected: impl Into<String>) -> ParseError {
+        let length = self.text[index..].chars().next().map_or(0, char::len_utf8);
+        self.error_at(&self.text[index..index + length], expected)
+    }
+
+    /// Error about something missing at the end of the line.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(&self.text[self.text.len()..], expected)
+    }
+
+    /// Parses `part`, a slice of the line.
+    pub fn parse<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        part.parse().map_err(|_| self.error_at(part, expected))
+    }
+
+    /// Matches the whole line against `regex`.
+    pub fn captures(&self, regex: &Regex, expected: &str) -> Result<Captures<'a>, ParseError> {
+        regex
+            .captures(self.text)
+            .ok_or_else(|| self.error(expected))
+    }
+}
+
+impl ParseError {
+    /// Error about missing content after the last line of `input`.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> ParseError {
+        let line = lines(input).last().unwrap_or(Line {
+            number: 1,
+            text: "",
+        });
+        ParseError {
+            column: line.text.chars().count() + 1,
+            length: 1,
+            ..line.error(expected)
+        }
+    }
+
+    pub fn with_file(self, file: impl Into<PathBuf>) -> ParseError {
+        ParseError {
+            file: Some(file.into()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self
+            .file
+            .as_ref()
+            .map_or_else(|| String::from("input"), |file| file.display().to_string());
+        let line_number = self.line_number.to_string();
+        let margin = " ".repeat(line_number.len());
+
+        writeln!(
+            f,
+            "{file}:{}:{}: expected {}",
+            self.line_number, self.column, self.expected
+        )?;
+        writeln!(f, "{margin} |")?;
+        writeln!(f, "{line_number} | {}", self.line)?;
+        write!(
+            f,
+            "{margin} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.length.max(1))
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_at_part() {
+        let line = lines("a\nSensor at x=12a").nth(1).unwrap();
+        let error = line.parse::<i32>(&line.text[12..], "a number").unwrap_err();
+
+        assert_eq!(
+            error.with_file("real.txt").to_string(),
+            "real.txt:2:13: expected a number\n  |\n2 | Sensor at x=12a\n  |             ^^^"
+        );
+    }
+
+    #[test]
+    fn test_error_at_foreign_part() {
+        let line = lines("abc").next().unwrap();
+        let error = line.error_at(&String::from("b"), "nothing");
+
+        assert_eq!((error.column, error.length), (1, 3));
+    }
+
+    #[test]
+    fn test_error_at_end() {
+        let error = ParseError::at_end("abc\nde\n", "a path");
+
+        assert_eq!(
+            error.to_string(),
+            "input:2:3: expected a path\n  |\n2 | de\n  |   ^"
+        );
+    }
+}
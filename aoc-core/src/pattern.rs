@@ -0,0 +1,400 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+use regex::Regex;
+
+use crate::parse::{Line, ParseError};
+
+/// Format of a line, with `{label}` placeholders for its fields, such as
+/// `"{name} can fly {u32} km/s for {u32} seconds"`. Placeholders labelled with an integer type
+/// only match digits, with a sign for signed types; other ones match any text, as little as
+/// possible. `{{` and `}}` stand for braces.
+///
+/// `T` is the tuple of values the fields are parsed into, with one value by placeholder.
+#[derive(Debug, Clone)]
+pub struct Pattern<T> {
+    text: String,
+    parts: Vec<Part>,
+    regex: Regex,
+    fields: PhantomData<fn() -> T>,
+}
+
+/// Pattern that cannot be built, with its text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    UnbalancedBraces(String),
+    /// Placeholders following each other without text between them, which could split their
+    /// fields anywhere.
+    AdjacentPlaceholders(String),
+    /// Number of placeholders different from the number of values of the tuple.
+    FieldCount {
+        pattern: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Field(String),
+}
+
+/// Value parsed from a field of a line matching a [`Pattern`]: numbers, strings, characters,
+/// or `&str` slices of the line to check them further and point errors at them.
+pub trait FromField<'a>: Sized {
+    fn from_field(line: &Line<'a>, field: &'a str, label: &str) -> Result<Self, ParseError>;
+}
+
+/// Values parsed from every field of a line matching a [`Pattern`], implemented for tuples of
+/// [`FromField`] values.
+pub trait FromFields<'a>: Sized {
+    /// Number of fields, one by value of the tuple.
+    const COUNT: usize;
+
+    /// Fields of a line, with the label of their placeholder.
+    fn from_fields(line: &Line<'a>, fields: &[(&'a str, &str)]) -> Result<Self, ParseError>;
+}
+
+const UNSIGNED_LABELS: [&str; 6] = ["u8", "u16", "u32", "u64", "u128", "usize"];
+const SIGNED_LABELS: [&str; 6] = ["i8", "i16", "i32", "i64", "i128", "isize"];
+
+impl<'a, T: FromFields<'a>> Pattern<T> {
+    /// Fails on unbalanced braces, on placeholders following each other without text between
+    /// them, or on a number of placeholders other than the number of values of `T`.
+    pub fn new(pattern: &str) -> Result<Pattern<T>, PatternError> {
+        let parts = parse_parts(pattern)?;
+        let field_count = parts
+            .iter()
+            .filter(|part| matches!(part, Part::Field(_)))
+            .count();
+        if field_count != T::COUNT {
+            return Err(PatternError::FieldCount {
+                pattern: String::from(pattern),
+                expected: T::COUNT,
+                found: field_count,
+            });
+        }
+        let regex = Regex::new(&format!("^{}$", to_regex(&parts))).unwrap();
+
+        Ok(Pattern {
+            text: String::from(pattern),
+            parts,
+            regex,
+            fields: PhantomData,
+        })
+    }
+}
+
+impl<T> Pattern<T> {
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+
+    /// Error about the first part of the pattern that `line` does not match.
+    fn mismatch_error(&self, line: &Line<'_>) -> ParseError {
+        // The longest prefix of the pattern matching the start of the line tells where it
+        // stops matching, and which part was expected there.
+        let Some((length, mut end)) = (0..self.parts.len()).rev().find_map(|length| {
+            let regex = Regex::new(&format!("^{}", to_regex(&self.parts[..length]))).unwrap();
+            regex.find(line.text).map(|prefix| (length, prefix.end()))
+        }) else {
+            return line.error(format!("a line like `{}`", self.text));
+        };
+
+        let expected = match &self.parts[length] {
+            Part::Literal(literal) => {
+                // A text field before the literal ends where most of the literal follows.
+                if let Some(Part::Field(label)) =
+                    length.checked_sub(1).map(|index| &self.parts[index])
+                {
+                    if !is_integer(label) {
+                        end = (end..=line.text.len())
+                            .filter(|&index| line.text.is_char_boundary(index))
+                            .max_by_key(|&index| {
+                                (
+                                    common_prefix_length(&line.text[index..], literal),
+                                    usize::MAX - index,
+                                )
+                            })
+                            .unwrap();
+                    }
+                }
+                end += common_prefix_length(&line.text[end..], literal);
+                format!("`{literal}`")
+            }
+            Part::Field(label) => describe(label),
+        };
+
+        let rest = &line.text[end..];
+        let part_length = rest
+            .find(char::is_whitespace)
+            .unwrap_or(rest.len())
+            .max(rest.chars().next().map_or(0, char::len_utf8));
+        line.error_at(&rest[..part_length], expected)
+    }
+}
+
+impl<'a> Line<'a> {
+    /// Matches the whole line against `pattern`, and parses its fields.
+    pub fn scan<T: FromFields<'a>>(&self, pattern: &Pattern<T>) -> Result<T, ParseError> {
+        let Some(captures) = pattern.regex.captures(self.text) else {
+            return Err(pattern.mismatch_error(self));
+        };
+
+        let labels = pattern.parts.iter().filter_map(|part| match part {
+            Part::Field(label) => Some(label.as_str()),
+            Part::Literal(_) => None,
+        });
+        let fields: Vec<(&'a str, &str)> = captures
+            .iter()
+            .skip(1)
+            .map(|field| field.unwrap().as_str())
+            .zip(labels)
+            .collect();
+
+        T::from_fields(self, &fields)
+    }
+}
+
+fn is_integer(label: &str) -> bool {
+    UNSIGNED_LABELS.contains(&label) || SIGNED_LABELS.contains(&label)
+}
+
+/// Length in bytes of the common start of both texts.
+fn common_prefix_length(text: &str, other: &str) -> usize {
+    text.char_indices()
+        .zip(other.chars())
+        .find(|((_, char), other_char)| char != other_char)
+        .map_or_else(|| text.len().min(other.len()), |((index, _), _)| index)
+}
+
+/// What a field of a placeholder is expected to be, in errors.
+fn describe(label: &str) -> String {
+    if is_integer(label) {
+        String::from("a number")
+    } else {
+        format!("`{{{label}}}`")
+    }
+}
+
+fn parse_parts(pattern: &str) -> Result<Vec<Part>, PatternError> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut label = String::new();
+                let mut is_closed = false;
+                for char in chars.by_ref() {
+                    if char == '}' {
+                        is_closed = true;
+                        break;
+                    }
+                    label.push(char);
+                }
+                if !is_closed || label.is_empty() || label.contains('{') {
+                    return Err(PatternError::UnbalancedBraces(String::from(pattern)));
+                }
+                if !literal.is_empty() {
+                    parts.push(Part::Literal(std::mem::take(&mut literal)));
+                }
+                if matches!(parts.last(), Some(Part::Field(_))) {
+                    return Err(PatternError::AdjacentPlaceholders(String::from(pattern)));
+                }
+                parts.push(Part::Field(label));
+            }
+            '}' => return Err(PatternError::UnbalancedBraces(String::from(pattern))),
+            _ => literal.push(char),
+        }
+    }
+    if !literal.is_empty() {
+        parts.push(Part::Literal(literal));
+    }
+
+    Ok(parts)
+}
+
+fn to_regex(parts: &[Part]) -> String {
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::Literal(literal) => regex::escape(literal),
+            Part::Field(label) if UNSIGNED_LABELS.contains(&label.as_str()) => {
+                String::from(r"(\+?\d+)")
+            }
+            Part::Field(label) if SIGNED_LABELS.contains(&label.as_str()) => {
+                String::from(r"([+-]?\d+)")
+            }
+            Part::Field(_) => String::from("(.+?)"),
+        })
+        .collect()
+}
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(_: &Line<'a>, field: &'a str, _: &str) -> Result<&'a str, ParseError> {
+        Ok(field)
+    }
+}
+
+macro_rules! impl_from_field {
+    ($($type:ty),+) => {
+        $(
+            impl<'a> FromField<'a> for $type {
+                fn from_field(line: &Line<'a>, field: &'a str, label: &str) -> Result<$type, ParseError> {
+                    line.parse(field, &describe(label))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_field!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+impl_from_field!(char, bool, String);
+
+macro_rules! impl_from_fields {
+    ($count:literal: $($type:ident $index:tt),+) => {
+        impl<'a, $($type: FromField<'a>),+> FromFields<'a> for ($($type,)+) {
+            const COUNT: usize = $count;
+
+            fn from_fields(line: &Line<'a>, fields: &[(&'a str, &str)]) -> Result<Self, ParseError> {
+                Ok(($($type::from_field(line, fields[$index].0, fields[$index].1)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1: A 0);
+impl_from_fields!(2: A 0, B 1);
+impl_from_fields!(3: A 0, B 1, C 2);
+impl_from_fields!(4: A 0, B 1, C 2, D 3);
+impl_from_fields!(5: A 0, B 1, C 2, D 3, E 4);
+impl_from_fields!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_fields!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_fields!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::UnbalancedBraces(pattern) => {
+                write!(f, "Unbalanced braces in pattern `{pattern}`")
+            }
+            PatternError::AdjacentPlaceholders(pattern) => {
+                write!(
+                    f,
+                    "Placeholders without text between them in pattern `{pattern}`"
+                )
+            }
+            PatternError::FieldCount {
+                pattern,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Pattern `{pattern}` has {found} placeholders instead of {expected}"
+            ),
+        }
+    }
+}
+
+impl Error for PatternError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn line(text: &str) -> Line<'_> {
+        parse::lines(text).next().unwrap()
+    }
+
+    #[test]
+    fn test_scan() {
+        let pattern = Pattern::new("{name} can fly {u32} km/s for {i8} seconds").unwrap();
+
+        assert_eq!(
+            line("Comet can fly 14 km/s for -10 seconds").scan(&pattern),
+            Ok((String::from("Comet"), 14_u32, -10_i8))
+        );
+        assert_eq!(
+            line("turn off 1,2").scan(&Pattern::new("{order} {usize},{usize}").unwrap()),
+            Ok(("turn off", 1_usize, 2_usize))
+        );
+        assert_eq!(
+            line("{7}").scan(&Pattern::new("{{{u8}}}").unwrap()),
+            Ok((7_u8,))
+        );
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let pattern = Pattern::new("{name} can fly {u32} km/s for {u8} seconds").unwrap();
+
+        let error = line("Comet can fly 14 km/h for 10 seconds")
+            .scan::<(String, u32, u8)>(&pattern)
+            .unwrap_err();
+        assert_eq!((error.column, error.length), (21, 1));
+        assert_eq!(error.expected, "` km/s for `");
+
+        let error = line("Comet can run")
+            .scan::<(String, u32, u8)>(&pattern)
+            .unwrap_err();
+        assert_eq!((error.column, error.length), (11, 3));
+
+        let error = line("Comet can fly fast")
+            .scan::<(String, u32, u8)>(&pattern)
+            .unwrap_err();
+        assert_eq!((error.column, error.length), (15, 4));
+        assert_eq!(error.expected, "a number");
+
+        let error = line("Comet can fly 14 km/s for 300 seconds")
+            .scan::<(String, u32, u8)>(&pattern)
+            .unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (27, "a number"));
+
+        let pattern = Pattern::new("{name} can fly {u32} km/s for {u8} seconds").unwrap();
+        let error = line("Comet can fly 14 km/s for 3 seconds")
+            .scan::<(char, u32, u8)>(&pattern)
+            .unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (1, "`{name}`"));
+    }
+
+    #[test]
+    fn test_pattern_errors() {
+        assert_eq!(
+            Pattern::<(u8, u8)>::new("{u8}{u8}").unwrap_err(),
+            PatternError::AdjacentPlaceholders(String::from("{u8}{u8}"))
+        );
+        assert_eq!(
+            Pattern::<(u8,)>::new("{u8}}").unwrap_err(),
+            PatternError::UnbalancedBraces(String::from("{u8}}"))
+        );
+        assert_eq!(
+            Pattern::<(u8, u8)>::new("{u8} and {u8} or {u8}")
+                .unwrap_err()
+                .to_string(),
+            "Pattern `{u8} and {u8} or {u8}` has 3 placeholders instead of 2"
+        );
+    }
+
+    #[test]
+    fn test_unclosed_brace() {
+        assert_eq!(
+            Pattern::<(u8,)>::new("x {u8").unwrap_err(),
+            PatternError::UnbalancedBraces(String::from("x {u8"))
+        );
+    }
+}
@@ -0,0 +1,437 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Integer type usable as a coordinate.
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Distance between two values, in the type of the values.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_coordinate {
+    ($($type:ty),*) => {
+        $(impl Coordinate for $type {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// 2D point. Like in a [`crate::Grid`], `y` grows downwards, so that [`Dir4::Up`] decreases
+/// it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// 3D point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Orthogonal direction, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// Orthogonal or diagonal direction, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+/// Text that is not comma separated coordinates, such as `498,4` or `2,2,5`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError;
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub fn manhattan_distance(self, other: Point2<T>) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// Distance when diagonal moves are allowed.
+    pub fn chebyshev_distance(self, other: Point2<T>) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// Neighbouring point in `direction`. Panics when an unsigned coordinate would go
+    /// below 0.
+    pub fn step(self, direction: Dir4) -> Point2<T> {
+        match direction {
+            Dir4::Up => Point2::new(self.x, self.y - T::ONE),
+            Dir4::Right => Point2::new(self.x + T::ONE, self.y),
+            Dir4::Down => Point2::new(self.x, self.y + T::ONE),
+            Dir4::Left => Point2::new(self.x - T::ONE, self.y),
+        }
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Point2<T> {
+    /// Quarter turn around the origin, clockwise on screen.
+    pub fn rotate_clockwise(self) -> Point2<T> {
+        Point2::new(-self.y, self.x)
+    }
+
+    pub fn rotate_counter_clockwise(self) -> Point2<T> {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// Point with each coordinate replaced by its sign: -1, 0 or 1.
+    pub fn signum(self) -> Point2<T> {
+        let signum = |value: T| match value.cmp(&T::ZERO) {
+            Ordering::Less => -T::ONE,
+            Ordering::Equal => T::ZERO,
+            Ordering::Greater => T::ONE,
+        };
+        Point2::new(signum(self.x), signum(self.y))
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn manhattan_distance(self, other: Point3<T>) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    /// Distance when diagonal moves are allowed.
+    pub fn chebyshev_distance(self, other: Point3<T>) -> T {
+        self.x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+
+    pub fn dot(self, other: Point3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The 6 points sharing a face with this one. Panics when an unsigned coordinate would
+    /// go below 0.
+    pub fn neighbours6(self) -> [Point3<T>; 6] {
+        let (x, y, z) = (self.x, self.y, self.z);
+        [
+            Point3::new(x - T::ONE, y, z),
+            Point3::new(x + T::ONE, y, z),
+            Point3::new(x, y - T::ONE, z),
+            Point3::new(x, y + T::ONE, z),
+            Point3::new(x, y, z - T::ONE),
+            Point3::new(x, y, z + T::ONE),
+        ]
+    }
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn clockwise(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn counter_clockwise(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// `(dx, dy)` of a step in this direction, as taken by [`crate::Grid::offset`].
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Dir4::Up => (0, -1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, 1),
+            Dir4::Left => (-1, 0),
+        }
+    }
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Eighth of a turn clockwise.
+    pub fn clockwise(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn counter_clockwise(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// `(dx, dy)` of a step in this direction, as taken by [`crate::Grid::offset`].
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Dir8 {
+        Dir8::ALL[direction as usize * 2]
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, factor: T) -> Point2<T> {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Point2<T> {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, factor: T) -> Point3<T> {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Point3<T> {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Point3<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Point3<T>) {
+        *self = *self - other;
+    }
+}
+
+/// Coordinates separated by commas, surrounding spaces allowed.
+fn parse_coordinates<T: FromStr, const N: usize>(text: &str) -> Result<[T; N], ParsePointError> {
+    let coordinates = text
+        .split(',')
+        .map(|coordinate| coordinate.trim().parse().map_err(|_| ParsePointError))
+        .collect::<Result<Vec<T>, ParsePointError>>()?;
+
+    coordinates.try_into().map_err(|_| ParsePointError)
+}
+
+/// Parses `x,y`, such as `498,4`.
+impl<T: FromStr> FromStr for Point2<T> {
+    type Err = ParsePointError;
+
+    fn from_str(text: &str) -> Result<Point2<T>, ParsePointError> {
+        let [x, y] = parse_coordinates(text)?;
+        Ok(Point2 { x, y })
+    }
+}
+
+/// Parses `x,y,z`, such as `2,2,5`.
+impl<T: FromStr> FromStr for Point3<T> {
+    type Err = ParsePointError;
+
+    fn from_str(text: &str) -> Result<Point3<T>, ParsePointError> {
+        let [x, y, z] = parse_coordinates(text)?;
+        Ok(Point3 { x, y, z })
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected comma separated coordinates")
+    }
+}
+
+impl Error for ParsePointError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let point = Point2::new(3, -2);
+
+        assert_eq!(point + Point2::new(1, 1), Point2::new(4, -1));
+        assert_eq!(point - Point2::new(1, 1), Point2::new(2, -3));
+        assert_eq!(point * 2, Point2::new(6, -4));
+        assert_eq!(-point, Point2::new(-3, 2));
+        assert_eq!(point.signum(), Point2::new(1, -1));
+        assert_eq!(Point3::new(1, 2, 3).dot(Point3::new(4, 5, 6)), 4 + 10 + 18);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(2_i32, 18);
+        let b = Point2::new(-2, 15);
+
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(
+            Point3::new(1_u64, 2, 3).manhattan_distance(Point3::new(3, 2, 1)),
+            4
+        );
+    }
+
+    #[test]
+    fn test_rotations() {
+        let right = Point2::new(1, 0);
+
+        assert_eq!(right.rotate_clockwise(), Point2::new(0, 1));
+        assert_eq!(right.rotate_counter_clockwise(), Point2::new(0, -1));
+        assert_eq!(Dir4::Left.clockwise(), Dir4::Up);
+        assert_eq!(Dir4::Up.counter_clockwise(), Dir4::Left);
+        assert_eq!(Dir8::UpLeft.clockwise(), Dir8::Up);
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::Down);
+        assert_eq!(Point2::new(0_u32, 5).step(Dir4::Up), Point2::new(0, 4));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("498,4".parse(), Ok(Point2::new(498_u32, 4)));
+        assert_eq!("2, 2, -5".parse(), Ok(Point3::new(2_i64, 2, -5)));
+        assert_eq!("498".parse::<Point2<u32>>(), Err(ParsePointError));
+        assert_eq!("1,2,3".parse::<Point2<u32>>(), Err(ParsePointError));
+    }
+}
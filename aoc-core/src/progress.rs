@@ -0,0 +1,330 @@
+//! Progress of long loops: a bar on stderr while they run, when it is a terminal, and a report
+//! of their throughput once they are done, collected by the runner.
+//!
+//! Reports of every thread go to the same sink, so that the runner also collects the loops of
+//! days solved on worker threads. It tells them apart by the name of their thread.
+
+use std::{
+    fmt::{self, Display},
+    io::{self, IsTerminal, Write},
+    mem,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Time between two redraws of a bar.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+const BAR_WIDTH: usize = 30;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Reports of the loops run by any thread since the last [`take_reports`].
+static REPORTS: Mutex<Vec<Report>> = Mutex::new(Vec::new());
+
+/// Iterator counting its items, drawing a bar of its progress if enabled.
+pub struct Progress<I> {
+    iterator: I,
+    label: String,
+    count: usize,
+    /// Number of items, if known from the start.
+    total: Option<usize>,
+    start: Instant,
+    bar: Option<Bar>,
+    is_reported: bool,
+}
+
+/// How often a bar looks at the clock. Looking at it for every item would slow down loops of
+/// cheap items, so the number of items between two looks adapts to the redraw interval.
+struct Bar {
+    last_draw: Instant,
+    next_check: usize,
+    stride: usize,
+}
+
+/// Number of items a loop went through, and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub label: String,
+    pub count: usize,
+    pub total: Option<usize>,
+    pub elapsed: Duration,
+    /// Name of the thread that ran the loop, if it has one.
+    pub thread: Option<String>,
+}
+
+pub trait ProgressIterator: Iterator + Sized {
+    /// Follows the progress of the iterator under `label`. Its total is known when the size
+    /// hint of the iterator is exact.
+    fn progress(self, label: impl Into<String>) -> Progress<Self>;
+}
+
+/// Turns the bars on or off, for every thread. Loops are still counted and reported.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Removes the `--no-progress` arguments, turning the bars off if there was one.
+pub fn take_no_progress(arguments: &mut Vec<String>) {
+    let count = arguments.len();
+    arguments.retain(|argument| argument != "--no-progress");
+    if arguments.len() < count {
+        set_enabled(false);
+    }
+}
+
+/// Reports of the loops run by any thread since the last call, in the order they ended.
+pub fn take_reports() -> Vec<Report> {
+    mem::take(&mut *REPORTS.lock().unwrap_or_else(|error| error.into_inner()))
+}
+
+impl<I: Iterator> ProgressIterator for I {
+    fn progress(self, label: impl Into<String>) -> Progress<I> {
+        let total = match self.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(upper),
+            _ => None,
+        };
+        let start = Instant::now();
+        let bar = (ENABLED.load(Ordering::Relaxed) && io::stderr().is_terminal()).then_some(Bar {
+            last_draw: start,
+            next_check: 1,
+            stride: 1,
+        });
+
+        Progress {
+            iterator: self,
+            label: label.into(),
+            count: 0,
+            total,
+            start,
+            bar,
+            is_reported: false,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Progress<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let Some(item) = self.iterator.next() else {
+            self.finish();
+            return None;
+        };
+
+        self.count += 1;
+        if self
+            .bar
+            .as_ref()
+            .is_some_and(|bar| self.count >= bar.next_check)
+        {
+            self.check_bar();
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<I> Progress<I> {
+    fn report(&self) -> Report {
+        Report {
+            label: self.label.clone(),
+            count: self.count,
+            total: self.total,
+            elapsed: self.start.elapsed(),
+            thread: thread::current().name().map(String::from),
+        }
+    }
+
+    fn check_bar(&mut self) {
+        let now = Instant::now();
+        let bar = self.bar.as_mut().unwrap();
+        let since_draw = now - bar.last_draw;
+        if since_draw < REDRAW_INTERVAL / 2 {
+            bar.stride = bar.stride.saturating_mul(2);
+        } else if since_draw > REDRAW_INTERVAL * 2 && bar.stride > 1 {
+            bar.stride /= 2;
+        }
+        bar.next_check = self.count.saturating_add(bar.stride);
+
+        if since_draw >= REDRAW_INTERVAL {
+            bar.last_draw = now;
+            let line = self.report().bar();
+            let _ = write!(io::stderr().lock(), "\r{line}\x1b[K");
+        }
+    }
+
+    /// Clears the bar and keeps the report of the loop, once.
+    fn finish(&mut self) {
+        if self.is_reported {
+            return;
+        }
+        self.is_reported = true;
+
+        if self.bar.is_some() {
+            let _ = write!(io::stderr().lock(), "\r\x1b[K");
+        }
+        let report = self.report();
+        // A loop may end while its thread unwinds from a panic, which must not panic again.
+        REPORTS
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .push(report);
+    }
+}
+
+/// Loops left before their end, such as a search returning as soon as it finds, are reported
+/// when dropped.
+impl<I> Drop for Progress<I> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+impl Report {
+    /// Items by second.
+    pub fn throughput(&self) -> f64 {
+        self.count as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Time the rest of the items would take at the same throughput, if the total is known.
+    pub fn eta(&self) -> Option<Duration> {
+        let remaining = self.total?.saturating_sub(self.count);
+        Some(Duration::from_secs_f64(
+            remaining as f64 / self.throughput().max(f64::EPSILON),
+        ))
+    }
+
+    /// Line drawn while the loop runs.
+    fn bar(&self) -> String {
+        let rate = SiNumber(self.throughput());
+        match (self.total, self.eta()) {
+            (Some(total), Some(eta)) if total > 0 => {
+                let filled = (self.count * BAR_WIDTH / total).min(BAR_WIDTH);
+                format!(
+                    "{} [{}{}] {:>3}% {}/{} {rate}/s ETA {:.0?}",
+                    self.label,
+                    "#".repeat(filled),
+                    " ".repeat(BAR_WIDTH - filled),
+                    self.count * 100 / total,
+                    self.count,
+                    total,
+                    eta
+                )
+            }
+            _ => format!("{} {} {rate}/s", self.label, self.count),
+        }
+    }
+}
+
+/// Summary line, with what was left when the loop ended before its total.
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.label, self.count)?;
+        if let Some(total) = self.total {
+            write!(f, " of {total}")?;
+        }
+        write!(
+            f,
+            " in {:.2?}, {}/s",
+            self.elapsed,
+            SiNumber(self.throughput())
+        )?;
+        match self.eta() {
+            Some(eta) if !eta.is_zero() => write!(f, ", stopped {eta:.2?} before the end"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Number written with a metric prefix, such as `2.50M`.
+struct SiNumber(f64);
+
+impl Display for SiNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.0;
+        for prefix in ["", "k", "M"] {
+            if value < 1000.0 {
+                return write!(f, "{value:.2}{prefix}");
+            }
+            value /= 1000.0;
+        }
+        write!(f, "{value:.2}G")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The only test running loops, as reports of every thread go to the same sink.
+    #[test]
+    fn test_progress() {
+        take_reports();
+
+        let sum: u32 = (1..=100).progress("sum").sum();
+        let found = (0..1000).progress("find").find(|&value| value == 9);
+        let unknown = (0..10)
+            .filter(|value| value % 2 == 0)
+            .progress("evens")
+            .count();
+        thread::Builder::new()
+            .name(String::from("worker"))
+            .spawn(|| (0..3).progress("worker loop").count())
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert_eq!((sum, found, unknown), (5050, Some(9), 5));
+        let reports = take_reports();
+        let summaries: Vec<(&str, usize, Option<usize>)> = reports
+            .iter()
+            .map(|report| (report.label.as_str(), report.count, report.total))
+            .collect();
+        assert_eq!(
+            summaries,
+            [
+                ("sum", 100, Some(100)),
+                ("find", 10, Some(1000)),
+                ("evens", 5, None),
+                ("worker loop", 3, Some(3))
+            ]
+        );
+        assert_eq!(reports[3].thread.as_deref(), Some("worker"));
+        assert_eq!(reports[0].eta(), Some(Duration::ZERO));
+        assert_eq!(reports[2].eta(), None);
+        assert!(take_reports().is_empty());
+    }
+
+    #[test]
+    fn test_report() {
+        let report = Report {
+            label: String::from("rows"),
+            count: 2_500_000,
+            total: Some(5_000_000),
+            elapsed: Duration::from_secs(2),
+            thread: None,
+        };
+
+        assert_eq!(report.throughput(), 1_250_000.0);
+        assert_eq!(report.eta(), Some(Duration::from_secs(2)));
+        assert_eq!(
+            report.to_string(),
+            "rows: 2500000 of 5000000 in 2.00s, 1.25M/s, stopped 2.00s before the end"
+        );
+        assert_eq!(
+            report.bar(),
+            format!(
+                "rows [{}{}]  50% 2500000/5000000 1.25M/s ETA 2s",
+                "#".repeat(15),
+                " ".repeat(15)
+            )
+        );
+    }
+}
@@ -0,0 +1,347 @@
+//! Images of simulations: frames mapping cells to colours, written as PPM or PNG, and
+//! animations of successive frames, written as GIF.
+
+use std::{
+    borrow::Cow,
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+    iter,
+    path::Path,
+    time::Duration,
+};
+
+use crate::{Grid, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// Image of a step of a simulation, one pixel by cell until scaled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+/// Successive frames of the same size, each one shown `delay` after the previous one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    delay: Duration,
+}
+
+/// Solution whose simulation can be replayed as an animation.
+pub trait Animate: Solution {
+    fn animate(input: &Self::Input) -> Animation;
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const DARK_GREY: Rgb = Rgb(40, 40, 40);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(250, 200, 40);
+    pub const ORANGE: Rgb = Rgb(203, 75, 22);
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Frame {
+        Frame {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Frame with a pixel by cell of `grid`, coloured by `palette`.
+    pub fn from_grid<T>(grid: &Grid<T>, palette: impl Fn(&T) -> Rgb) -> Frame {
+        Frame {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.rows().flatten().map(palette).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Panics outside of the frame.
+    pub fn get(&self, (x, y): (usize, usize)) -> Rgb {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside of the frame"
+        );
+        self.pixels[y * self.width + x]
+    }
+
+    /// Panics outside of the frame.
+    pub fn set(&mut self, (x, y): (usize, usize), color: Rgb) {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside of the frame"
+        );
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Frame with each pixel drawn as a square of `factor` pixels by side.
+    pub fn scaled(&self, factor: usize) -> Frame {
+        let mut pixels = Vec::with_capacity(self.pixels.len() * factor * factor);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let scaled_row: Vec<Rgb> = row
+                .iter()
+                .flat_map(|&pixel| iter::repeat_n(pixel, factor))
+                .collect();
+            for _ in 0..factor {
+                pixels.extend_from_slice(&scaled_row);
+            }
+        }
+
+        Frame {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// Binary PPM, `P6`: a tiny header then the raw pixels.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.bytes())?;
+        writer.flush()
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(
+            writer,
+            dimension(self.width, u32::MAX)?,
+            dimension(self.height, u32::MAX)?,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.bytes())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Writes the frame as PPM or PNG, depending on the extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match extension(path).as_deref() {
+            Some("ppm") => self.write_ppm(create(path)?),
+            Some("png") => self.write_png(create(path)?),
+            _ => Err(unsupported_extension("`.ppm` or `.png`")),
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(red, green, blue)| [red, green, blue])
+            .collect()
+    }
+}
+
+impl Animation {
+    pub fn new(delay: Duration) -> Animation {
+        Animation {
+            frames: Vec::new(),
+            delay,
+        }
+    }
+
+    /// Adds a frame after the others. Panics if it is not the size of the first one.
+    pub fn push(&mut self, frame: Frame) {
+        if let Some(first) = self.frames.first() {
+            assert!(
+                (frame.width, frame.height) == (first.width, first.height),
+                "frames of an animation should all be {}x{}",
+                first.width,
+                first.height
+            );
+        }
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Looping GIF of the frames. GIFs have a palette of 256 colours at most, for the whole
+    /// animation, and a delay rounded to hundredths of a second.
+    pub fn write_gif(&self, writer: impl Write) -> io::Result<()> {
+        let Some(first) = self.frames.first() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "an animation needs at least one frame",
+            ));
+        };
+        let (width, height) = (
+            dimension(first.width, u16::MAX)?,
+            dimension(first.height, u16::MAX)?,
+        );
+
+        let mut palette: Vec<Rgb> = Vec::new();
+        let mut indices: HashMap<Rgb, u8> = HashMap::new();
+        for &pixel in self.frames.iter().flat_map(|frame| &frame.pixels) {
+            if let Entry::Vacant(entry) = indices.entry(pixel) {
+                let index = u8::try_from(palette.len()).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "a GIF cannot have more than 256 colours",
+                    )
+                })?;
+                entry.insert(index);
+                palette.push(pixel);
+            }
+        }
+        let palette_bytes: Vec<u8> = palette
+            .iter()
+            .flat_map(|&Rgb(red, green, blue)| [red, green, blue])
+            .collect();
+
+        let mut encoder =
+            gif::Encoder::new(writer, width, height, &palette_bytes).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        let delay = (self.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        for frame in &self.frames {
+            let buffer: Vec<u8> = frame.pixels.iter().map(|pixel| indices[pixel]).collect();
+            let frame = gif::Frame {
+                width,
+                height,
+                delay,
+                buffer: Cow::Owned(buffer),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the animation as GIF, or its last frame as PPM or PNG, depending on the
+    /// extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match extension(path).as_deref() {
+            Some("gif") => self.write_gif(create(path)?),
+            Some("ppm" | "png") => match self.frames.last() {
+                Some(frame) => frame.save(path),
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "an animation needs at least one frame",
+                )),
+            },
+            _ => Err(unsupported_extension("`.gif`, `.ppm` or `.png`")),
+        }
+    }
+}
+
+/// Parses the input and replays the simulation of a day.
+pub fn animate<S: Animate>(input: &str) -> Result<Animation, ParseError> {
+    S::parse(input).map(|input| S::animate(&input))
+}
+
+fn dimension<T: TryFrom<usize> + Display>(length: usize, max: T) -> io::Result<T> {
+    T::try_from(length).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{length} pixels is more than the {max} supported"),
+        )
+    })
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+}
+
+fn create(path: &Path) -> io::Result<BufWriter<File>> {
+    File::create(path).map(BufWriter::new)
+}
+
+fn unsupported_extension(expected: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("expected a {expected} file"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Frame {
+        let grid = Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+        Frame::from_grid(
+            &grid,
+            |&is_set| if is_set { Rgb::WHITE } else { Rgb::BLACK },
+        )
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame = checkerboard().scaled(2);
+
+        assert_eq!((frame.width(), frame.height()), (4, 4));
+        assert_eq!(frame.get((1, 1)), Rgb::WHITE);
+        assert_eq!(frame.get((2, 1)), Rgb::BLACK);
+        assert_eq!(frame.get((3, 3)), Rgb::WHITE);
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let mut bytes = Vec::new();
+        checkerboard().write_ppm(&mut bytes).unwrap();
+
+        assert_eq!(&bytes[..11], b"P6\n2 2\n255\n");
+        assert_eq!(
+            &bytes[11..],
+            [255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn test_write_png_and_gif() {
+        let mut bytes = Vec::new();
+        checkerboard().write_png(&mut bytes).unwrap();
+        assert_eq!(&bytes[1..4], b"PNG");
+
+        let mut animation = Animation::new(Duration::from_millis(100));
+        let mut inverted = checkerboard();
+        inverted.set((0, 0), Rgb::BLACK);
+        animation.push(checkerboard());
+        animation.push(inverted);
+
+        let mut bytes = Vec::new();
+        animation.write_gif(&mut bytes).unwrap();
+        assert_eq!(&bytes[..6], b"GIF89a");
+        assert_eq!(*bytes.last().unwrap(), b';');
+
+        assert!(Animation::new(Duration::ZERO)
+            .write_gif(Vec::new())
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "frames of an animation should all be 2x2")]
+    fn test_frames_of_different_sizes() {
+        let mut animation = Animation::new(Duration::ZERO);
+        animation.push(checkerboard());
+        animation.push(checkerboard().scaled(2));
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Cheapest way found from a start to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// Every state of the path, from the start to the goal both included.
+    pub states: Vec<S>,
+}
+
+/// Visited state, with the index of the state it was reached from.
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+}
+
+/// Queued node, the lowest estimate popping first from the heap.
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+/// Breadth first search from every start at once, until a state matching `is_goal`. The
+/// cost is the number of moves.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut nodes: Vec<Node<S, usize>> = Vec::new();
+    let mut indexes: HashMap<S, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !indexes.contains_key(&start) {
+            queue.push_back(push_node(&mut nodes, &mut indexes, start, None, 0));
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&nodes[index].state) {
+            return Some(reconstruct_path(nodes, index));
+        }
+
+        let cost = nodes[index].cost + 1;
+        for neighbour in neighbours(&nodes[index].state) {
+            if !indexes.contains_key(&neighbour) {
+                queue.push_back(push_node(
+                    &mut nodes,
+                    &mut indexes,
+                    neighbour,
+                    Some(index),
+                    cost,
+                ));
+            }
+        }
+    }
+
+    None
+}
+
+/// Cheapest path from any start to a state matching `is_goal`, given the cost of each move.
+/// Costs start from `C::default()`, and must not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], exploring first the states with the lowest cost plus `heuristic`. The
+/// path is the cheapest one as long as the heuristic never overestimates the remaining cost.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes: Vec<Node<S, C>> = Vec::new();
+    let mut indexes: HashMap<S, usize> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if !indexes.contains_key(&start) {
+            let estimate = heuristic(&start);
+            let index = push_node(&mut nodes, &mut indexes, start, None, C::default());
+            heap.push(Entry {
+                estimate,
+                cost: C::default(),
+                index,
+            });
+        }
+    }
+
+    while let Some(Entry { cost, index, .. }) = heap.pop() {
+        // A cheaper way to this state was queued after this entry.
+        if cost > nodes[index].cost {
+            continue;
+        }
+        if is_goal(&nodes[index].state) {
+            return Some(reconstruct_path(nodes, index));
+        }
+
+        for (neighbour, move_cost) in neighbours(&nodes[index].state) {
+            let neighbour_cost = cost + move_cost;
+            let neighbour_index = match indexes.get(&neighbour) {
+                Some(&known_index) if nodes[known_index].cost <= neighbour_cost => continue,
+                Some(&known_index) => {
+                    nodes[known_index].parent = Some(index);
+                    nodes[known_index].cost = neighbour_cost;
+                    known_index
+                }
+                None => push_node(
+                    &mut nodes,
+                    &mut indexes,
+                    neighbour,
+                    Some(index),
+                    neighbour_cost,
+                ),
+            };
+
+            heap.push(Entry {
+                estimate: neighbour_cost + heuristic(&nodes[neighbour_index].state),
+                cost: neighbour_cost,
+                index: neighbour_index,
+            });
+        }
+    }
+
+    None
+}
+
+/// Every state reachable from the starts, the starts included.
+pub fn reachable<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited: HashSet<S> = HashSet::new();
+    let mut stack: Vec<S> = Vec::new();
+
+    for start in starts {
+        if visited.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+
+    while let Some(state) = stack.pop() {
+        for neighbour in neighbours(&state) {
+            if visited.insert(neighbour.clone()) {
+                stack.push(neighbour);
+            }
+        }
+    }
+
+    visited
+}
+
+fn push_node<S: Clone + Eq + Hash, C>(
+    nodes: &mut Vec<Node<S, C>>,
+    indexes: &mut HashMap<S, usize>,
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+) -> usize {
+    let index = nodes.len();
+    indexes.insert(state.clone(), index);
+    nodes.push(Node {
+        state,
+        parent,
+        cost,
+    });
+    index
+}
+
+fn reconstruct_path<S, C: Copy>(mut nodes: Vec<Node<S, C>>, goal_index: usize) -> Path<S, C> {
+    let cost = nodes[goal_index].cost;
+
+    let mut indexes = vec![goal_index];
+    while let Some(parent) = nodes[*indexes.last().unwrap()].parent {
+        indexes.push(parent);
+    }
+
+    let mut states: Vec<Option<S>> = nodes.drain(..).map(|node| Some(node.state)).collect();
+    Path {
+        cost,
+        states: indexes
+            .into_iter()
+            .rev()
+            .map(|index| states[index].take().unwrap())
+            .collect(),
+    }
+}
+
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "S.#.\n..#.\n#...\n..#G\n";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, "a maze tile", Some).unwrap()
+    }
+
+    fn open_neighbours(maze: &Grid<char>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        maze.neighbours4(position)
+            .filter(|&neighbour| maze[neighbour] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let goal = maze.find(&'G').unwrap();
+
+        let path = bfs(
+            [maze.find(&'S').unwrap()],
+            |&position| open_neighbours(&maze, position),
+            |&position| position == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.len(), 7);
+        assert_eq!(path.states[0], (0, 0));
+        assert_eq!(path.states[6], goal);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|pair| open_neighbours(&maze, pair[0]).contains(&pair[1])));
+
+        let nearest = bfs([(3, 0), (0, 0)], |_| Vec::new(), |&(x, _)| x == 3).unwrap();
+        assert_eq!(nearest.states, vec![(3, 0)]);
+        assert_eq!(
+            bfs([(0, 0)], |_| Vec::new(), |&position| position == goal),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Going through `b` costs 2 + 2, going straight to `c` costs 5.
+        let edges = |&state: &char| match state {
+            'a' => vec![('b', 2), ('c', 5)],
+            'b' => vec![('c', 2)],
+            _ => vec![],
+        };
+
+        let path = dijkstra(['a'], edges, |&state| state == 'c').unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states, vec!['a', 'b', 'c']);
+
+        let maze = maze();
+        let goal = maze.find(&'G').unwrap();
+        let path = astar(
+            [(0, 0)],
+            |&position| {
+                open_neighbours(&maze, position)
+                    .into_iter()
+                    .map(|neighbour| (neighbour, 1))
+            },
+            |&(x, y)| x.abs_diff(goal.0) + y.abs_diff(goal.1),
+            |&position| position == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 6);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let maze = maze();
+
+        let open = reachable([(3, 0)], |&position| open_neighbours(&maze, position));
+
+        assert_eq!(
+            open.len(),
+            MAZE.chars()
+                .filter(|&char| char != '#' && char != '\n')
+                .count()
+        );
+    }
+}
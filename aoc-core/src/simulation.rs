@@ -0,0 +1,156 @@
+//! Simulations advanced tick by tick, and the timeline of their past ticks to step back
+//! through them.
+
+use crate::{Grid, Solution};
+
+/// Ticks between two states kept by a [`Timeline`].
+const CHECKPOINT_INTERVAL: usize = 100;
+
+/// State of a simulation, advanced one tick at a time.
+pub trait Simulation: Clone {
+    /// Advances by one tick. Returns `false`, leaving the state unchanged, once the
+    /// simulation is over.
+    fn step(&mut self) -> bool;
+
+    /// Current state, a character by cell.
+    fn render(&self) -> Grid<char>;
+
+    /// What a cell of the rendered grid holds, with its position in the puzzle.
+    fn inspect(&self, cell: (usize, usize)) -> String;
+}
+
+/// Solution whose simulation can be stepped through.
+pub trait Simulate: Solution {
+    type Simulation<'a>: Simulation
+    where
+        Self: 'a;
+
+    fn simulate(input: &Self::Input) -> Self::Simulation<'_>;
+}
+
+/// Simulation with a state kept every hundred ticks, stepping back by replaying from the last
+/// one before.
+#[derive(Debug, Clone)]
+pub struct Timeline<S> {
+    /// States after `0`, `CHECKPOINT_INTERVAL`, `2 * CHECKPOINT_INTERVAL`... ticks.
+    checkpoints: Vec<S>,
+    current: S,
+    tick: usize,
+    /// Tick after which the simulation is over, once reached.
+    last_tick: Option<usize>,
+}
+
+impl<S: Simulation> Timeline<S> {
+    pub fn new(initial: S) -> Timeline<S> {
+        Timeline {
+            checkpoints: vec![initial.clone()],
+            current: initial,
+            tick: 0,
+            last_tick: None,
+        }
+    }
+
+    pub fn current(&self) -> &S {
+        &self.current
+    }
+
+    /// Number of ticks from the initial state.
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.last_tick == Some(self.tick)
+    }
+
+    /// Advances by `ticks` ticks, or until the simulation is over. Returns the number of ticks
+    /// advanced.
+    pub fn forward(&mut self, ticks: usize) -> usize {
+        let start = self.tick;
+        for _ in 0..ticks {
+            if self.is_over() {
+                break;
+            }
+            if !self.current.step() {
+                self.last_tick = Some(self.tick);
+                break;
+            }
+
+            self.tick += 1;
+            if self.tick == self.checkpoints.len() * CHECKPOINT_INTERVAL {
+                self.checkpoints.push(self.current.clone());
+            }
+        }
+        self.tick - start
+    }
+
+    /// Goes back by `ticks` ticks, or to the initial state.
+    pub fn back(&mut self, ticks: usize) {
+        self.seek(self.tick.saturating_sub(ticks));
+    }
+
+    /// Goes to the state after `tick` ticks, or to the last one if the simulation is over
+    /// before.
+    pub fn seek(&mut self, tick: usize) {
+        if tick < self.tick {
+            let checkpoint = tick / CHECKPOINT_INTERVAL;
+            self.current = self.checkpoints[checkpoint].clone();
+            self.tick = checkpoint * CHECKPOINT_INTERVAL;
+        }
+        self.forward(tick - self.tick);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counter drawn as a bar, over once it reaches its limit.
+    #[derive(Debug, Clone)]
+    struct Counter {
+        value: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.value == self.limit {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+
+        fn render(&self) -> Grid<char> {
+            Grid::new(self.value, 1, '#')
+        }
+
+        fn inspect(&self, (x, _): (usize, usize)) -> String {
+            format!("{x}")
+        }
+    }
+
+    #[test]
+    fn test_timeline() {
+        let mut timeline = Timeline::new(Counter {
+            value: 0,
+            limit: 250,
+        });
+
+        assert_eq!(timeline.forward(120), 120);
+        assert_eq!(timeline.current().value, 120);
+        timeline.back(30);
+        assert_eq!((timeline.tick(), timeline.current().value), (90, 90));
+        timeline.seek(205);
+        assert_eq!((timeline.tick(), timeline.current().value), (205, 205));
+        timeline.back(1000);
+        assert_eq!((timeline.tick(), timeline.current().value), (0, 0));
+
+        assert_eq!(timeline.forward(1000), 250);
+        assert!(timeline.is_over());
+        assert_eq!(timeline.forward(1), 0);
+        timeline.back(1);
+        assert!(!timeline.is_over());
+        assert_eq!(timeline.current().render().width(), 249);
+    }
+}
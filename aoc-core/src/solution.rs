@@ -0,0 +1,120 @@
+use serde::Deserialize;
+use std::{
+    fmt::{self, Display},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::{Input, ParseError};
+
+/// Solution of a day, answering both parts from the same parsed input.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// Answer of a part that some well-formed inputs do not have, such as a search finding nothing.
+/// Written as [`NO_ANSWER`] when missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+
+/// How a missing [`MaybeAnswer`] is written.
+pub const NO_ANSWER: &str = "no answer";
+
+/// Answers of both parts of a day.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Time spent in each phase of a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Parses the input once and answers both parts.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, ParseError> {
+    solve_timed::<S>(input).map(|(answers, _)| answers)
+}
+
+/// Parses the input once and answers both parts, timing each phase.
+pub fn solve_timed<S: Solution>(input: &str) -> Result<(Answers, Timings), ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&input).to_string();
+    let part1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = S::part2(&input).to_string();
+    let part2_duration = start.elapsed();
+
+    let timings = Timings {
+        parse,
+        part1: part1_duration,
+        part2: part2_duration,
+    };
+    Ok((Answers { part1, part2 }, timings))
+}
+
+/// Prints the answers of a day, or where its input is malformed.
+pub fn run<S: Solution>(input: &Input) -> ExitCode {
+    match solve::<S>(input.as_str()) {
+        Ok(answers) => {
+            println!("{answers}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error.with_file(input.path()));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => f.write_str(NO_ANSWER),
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_answer(f, 1, &self.part1)?;
+        writeln!(f)?;
+        write_answer(f, 2, &self.part2)
+    }
+}
+
+/// Multi-line answers, such as letters drawn on a screen, start on their own line.
+fn write_answer(f: &mut fmt::Formatter<'_>, part: u8, answer: &str) -> fmt::Result {
+    if answer.contains('\n') {
+        write!(f, "Part {part}:\n{answer}")
+    } else {
+        write!(f, "Part {part}: {answer}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maybe_answer() {
+        assert_eq!(MaybeAnswer(Some(42)).to_string(), "42");
+        assert_eq!(MaybeAnswer::<u32>(None).to_string(), NO_ANSWER);
+    }
+}
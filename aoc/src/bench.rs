@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use aoc_core::{ParseError, Timings};
+use serde::Serialize;
+
+use crate::days::Solver;
+
+/// How many times a day is run before and while being measured.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+/// Spread of the durations of a phase over the measured iterations, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Statistics {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+    pub std_dev_ns: u64,
+}
+
+/// Measures of the phases of a day on one input.
+#[derive(Debug, Clone, Serialize)]
+pub struct Benchmark {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub iterations: usize,
+    pub parse: Statistics,
+    pub part1: Statistics,
+    pub part2: Statistics,
+}
+
+const CSV_HEADER: &str = "year,day,input,phase,iterations,median_ns,min_ns,max_ns,std_dev_ns";
+
+/// Runs a solution on the input, discarding the warm-up runs, and returns the statistics of
+/// the parse, part 1 and part 2 phases.
+pub fn measure(
+    solve: Solver,
+    input: &str,
+    options: Options,
+) -> Result<[Statistics; 3], ParseError> {
+    for _ in 0..options.warmup {
+        solve(input)?;
+    }
+
+    let timings = (0..options.iterations)
+        .map(|_| solve(input).map(|(_, timings)| timings))
+        .collect::<Result<Vec<Timings>, ParseError>>()?;
+
+    Ok([
+        Statistics::new(timings.iter().map(|timings| timings.parse).collect()),
+        Statistics::new(timings.iter().map(|timings| timings.part1).collect()),
+        Statistics::new(timings.iter().map(|timings| timings.part2).collect()),
+    ])
+}
+
+impl Statistics {
+    fn new(mut durations: Vec<Duration>) -> Statistics {
+        durations.sort();
+        let nanos: Vec<f64> = durations
+            .iter()
+            .map(|duration| duration.as_nanos() as f64)
+            .collect();
+
+        let middle = nanos.len() / 2;
+        let median = if nanos.len().is_multiple_of(2) {
+            (nanos[middle - 1] + nanos[middle]) / 2.
+        } else {
+            nanos[middle]
+        };
+
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance =
+            nanos.iter().map(|nano| (nano - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Statistics {
+            median_ns: median.round() as u64,
+            min_ns: nanos[0] as u64,
+            max_ns: nanos[nanos.len() - 1] as u64,
+            std_dev_ns: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+impl Benchmark {
+    fn phases(&self) -> [(&'static str, &Statistics); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+
+    /// Human readable report, one line per phase.
+    pub fn report(&self) -> String {
+        let mut report = format!(
+            "{} day {:02} ({}), {} iterations",
+            self.year, self.day, self.input, self.iterations
+        );
+        for (phase, statistics) in self.phases() {
+            report.push_str(&format!(
+                "\n  {phase:<5}  median {:>10.2?}  min {:>10.2?}  max {:>10.2?}  std dev {:>10.2?}",
+                Duration::from_nanos(statistics.median_ns),
+                Duration::from_nanos(statistics.min_ns),
+                Duration::from_nanos(statistics.max_ns),
+                Duration::from_nanos(statistics.std_dev_ns),
+            ));
+        }
+        report
+    }
+}
+
+pub fn to_json(benchmarks: &[Benchmark]) -> String {
+    serde_json::to_string_pretty(benchmarks).unwrap()
+}
+
+/// CSV export, one row per phase of each benchmark.
+pub fn to_csv(benchmarks: &[Benchmark]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+
+    for benchmark in benchmarks {
+        for (phase, statistics) in benchmark.phases() {
+            csv.push_str(&format!(
+                "{},{},{},{phase},{},{},{},{},{}\n",
+                benchmark.year,
+                benchmark.day,
+                escape_csv(&benchmark.input),
+                benchmark.iterations,
+                statistics.median_ns,
+                statistics.min_ns,
+                statistics.max_ns,
+                statistics.std_dev_ns,
+            ));
+        }
+    }
+
+    csv
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        String::from(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statistics(nanos: &[u64]) -> Statistics {
+        Statistics::new(
+            nanos
+                .iter()
+                .map(|nano| Duration::from_nanos(*nano))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_statistics() {
+        assert_eq!(
+            statistics(&[30, 10, 20]),
+            Statistics {
+                median_ns: 20,
+                min_ns: 10,
+                max_ns: 30,
+                std_dev_ns: 8,
+            }
+        );
+        assert_eq!(statistics(&[10, 40, 20, 30]).median_ns, 25);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let phase = statistics(&[5]);
+        let benchmark = Benchmark {
+            year: 2022,
+            day: 15,
+            input: String::from("real,1.txt"),
+            iterations: 1,
+            parse: phase,
+            part1: phase,
+            part2: phase,
+        };
+
+        assert_eq!(
+            to_csv(&[benchmark]),
+            format!(
+                "{CSV_HEADER}\n\
+                2022,15,\"real,1.txt\",parse,1,5,5,5,0\n\
+                2022,15,\"real,1.txt\",part1,1,5,5,5,0\n\
+                2022,15,\"real,1.txt\",part2,1,5,5,5,0\n"
+            )
+        );
+    }
+}
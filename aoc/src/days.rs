@@ -0,0 +1,146 @@
+use crate::stepper::StepError;
+use aoc_core::{render::Animation, Answers, ParseError, Timings};
+use std::path::{Path, PathBuf};
+
+/// Answers a day from its input, timing each phase.
+pub type Solver = fn(&str) -> Result<(Answers, Timings), ParseError>;
+
+/// Replays the simulation of a day on its input.
+pub type Animator = fn(&str) -> Result<Animation, ParseError>;
+
+/// Steps through the simulation of a day in the terminal.
+pub type Stepper = fn(&str) -> Result<(), StepError>;
+
+/// How a day of the calendar is solved in this repository.
+pub enum Implementation {
+    Rust(Solver),
+    /// Solved outside of the Rust workspace, with the language and the solution file.
+    Other(&'static str, &'static str),
+    Missing,
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub implementation: Implementation,
+    /// Animation of the simulation of the day, for the days implementing
+    /// [`aoc_core::render::Animate`].
+    pub animator: Option<Animator>,
+    /// Terminal stepper of the simulation of the day, for the days implementing
+    /// [`aoc_core::simulation::Simulate`].
+    pub stepper: Option<Stepper>,
+}
+
+impl Day {
+    /// Directory of the day, holding its inputs.
+    pub fn directory(&self) -> PathBuf {
+        directory(self.year, self.day)
+    }
+}
+
+/// Directory of any day of the calendar, whether it is solved in this repository or not.
+pub fn directory(year: u16, day: u8) -> PathBuf {
+    workspace_root()
+        .join(year.to_string())
+        .join(format!("day{day:02}"))
+}
+
+/// Directory of the workspace manifest.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+macro_rules! rust {
+    ($year:literal, $day:literal, $crate_name:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            implementation: Implementation::Rust(aoc_core::solve_timed::<$crate_name::Day>),
+            animator: None,
+            stepper: None,
+        }
+    };
+    ($year:literal, $day:literal, $crate_name:ident, animated $(, $feature:ident)*) => {
+        Day {
+            animator: Some(aoc_core::render::animate::<$crate_name::Day>),
+            ..rust!($year, $day, $crate_name $(, $feature)*)
+        }
+    };
+    ($year:literal, $day:literal, $crate_name:ident, simulated $(, $feature:ident)*) => {
+        Day {
+            stepper: Some(crate::stepper::step::<$crate_name::Day>),
+            ..rust!($year, $day, $crate_name $(, $feature)*)
+        }
+    };
+}
+
+pub static DAYS: [Day; 50] = [
+    rust!(2015, 1, day01_2015),
+    rust!(2015, 2, day02_2015),
+    rust!(2015, 3, day03_2015),
+    rust!(2015, 4, day04_2015),
+    rust!(2015, 5, day05_2015),
+    rust!(2015, 6, day06_2015),
+    rust!(2015, 7, day07_2015),
+    Day {
+        year: 2015,
+        day: 8,
+        implementation: Implementation::Other("TypeScript", "index.ts"),
+        animator: None,
+        stepper: None,
+    },
+    rust!(2015, 9, day09_2015),
+    rust!(2015, 10, day10_2015),
+    rust!(2015, 11, day11_2015),
+    rust!(2015, 12, day12_2015),
+    rust!(2015, 13, day13_2015),
+    rust!(2015, 14, day14_2015),
+    rust!(2015, 15, day15_2015),
+    rust!(2015, 16, day16_2015),
+    rust!(2015, 17, day17_2015),
+    rust!(2015, 18, day18_2015, animated),
+    rust!(2015, 19, day19_2015),
+    rust!(2015, 20, day20_2015),
+    rust!(2015, 21, day21_2015),
+    rust!(2015, 22, day22_2015),
+    rust!(2015, 23, day23_2015),
+    rust!(2015, 24, day24_2015),
+    rust!(2015, 25, day25_2015),
+    rust!(2022, 1, day01_2022),
+    rust!(2022, 2, day02_2022),
+    rust!(2022, 3, day03_2022),
+    rust!(2022, 4, day04_2022),
+    rust!(2022, 5, day05_2022),
+    rust!(2022, 6, day06_2022),
+    rust!(2022, 7, day07_2022),
+    rust!(2022, 8, day08_2022),
+    rust!(2022, 9, day09_2022, animated, simulated),
+    rust!(2022, 10, day10_2022, animated),
+    rust!(2022, 11, day11_2022),
+    rust!(2022, 12, day12_2022),
+    rust!(2022, 13, day13_2022),
+    rust!(2022, 14, day14_2022, animated, simulated),
+    rust!(2022, 15, day15_2022),
+    rust!(2022, 16, day16_2022),
+    rust!(2022, 17, day17_2022, animated),
+    rust!(2022, 18, day18_2022),
+    rust!(2022, 19, day19_2022),
+    rust!(2022, 20, day20_2022),
+    rust!(2022, 21, day21_2022),
+    rust!(2022, 22, day22_2022),
+    rust!(2022, 23, day23_2022),
+    rust!(2022, 24, day24_2022, simulated),
+    rust!(2022, 25, day25_2022),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |entry| entry.year == year)
+}
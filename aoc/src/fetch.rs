@@ -0,0 +1,376 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+/// Overrides the path of the config file, `~/.config/aoc/config.toml` by default.
+const CONFIG_VARIABLE: &str = "AOC_CONFIG";
+
+/// Sent with every request, as asked by the Advent of Code automation guidelines.
+pub(crate) const USER_AGENT: &str = "github.com/capsuleman/advent-of-code";
+
+/// Settings of the config file, each one overridden by its environment variable.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+/// Downloads puzzle inputs, keeping a copy of each one in the cache directory of their server.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input file was already there with content, and was left untouched.
+    AlreadyPresent,
+    Cache,
+    Download,
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Config { path: PathBuf, message: String },
+    MissingSession,
+    Http { url: String, message: String },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl Fetcher {
+    /// Fetcher configured by the config file and the environment, with an optional base URL
+    /// overriding both.
+    pub fn from_environment(base_url: Option<String>) -> Result<Fetcher, FetchError> {
+        let config = Config::load()?;
+
+        Ok(Fetcher {
+            base_url: config.base_url(base_url),
+            session: config.session(),
+            cache_dir: cache_dir(),
+        })
+    }
+
+    /// Writes the input of a day to `destination`, from the cache when it was already
+    /// downloaded once. An existing destination is never overwritten, unless it is empty like
+    /// the one created with a new day.
+    pub fn fetch(&self, year: u16, day: u8, destination: &Path) -> Result<Fetched, FetchError> {
+        if fs::metadata(destination).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::AlreadyPresent);
+        }
+
+        let cache_path = self.cache_path(year, day);
+        let fetched = if cache_path.exists() {
+            Fetched::Cache
+        } else {
+            let input = self.download(year, day)?;
+            write(&cache_path, &input)?;
+            Fetched::Download
+        };
+
+        if let Some(directory) = destination.parent() {
+            fs::create_dir_all(directory).map_err(|error| io_error(directory, error))?;
+        }
+        fs::copy(&cache_path, destination).map_err(|error| io_error(destination, error))?;
+        Ok(fetched)
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{year}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        )
+    }
+
+    fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(endpoint_directory(&self.base_url))
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = self.input_url(year, day);
+        let http_error = |message: String| FetchError::Http {
+            url: url.clone(),
+            message,
+        };
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(status, response) => {
+                    http_error(format!("status {status} {}", response.status_text()))
+                }
+                ureq::Error::Transport(transport) => http_error(describe_transport(&transport)),
+            })?;
+
+        response
+            .into_string()
+            .map_err(|error| http_error(error.to_string()))
+    }
+}
+
+/// Transport error without its URL, such as `Connection Failed: Connection refused`.
+pub(crate) fn describe_transport(transport: &ureq::Transport) -> String {
+    let mut description = transport.kind().to_string();
+    if let Some(message) = transport.message() {
+        description.push_str(&format!(": {message}"));
+    }
+    if let Some(source) = transport.source() {
+        description.push_str(&format!(": {source}"));
+    }
+    description
+}
+
+impl Config {
+    /// Reads the config file, when there is one.
+    pub fn load() -> Result<Config, FetchError> {
+        let Some(path) = env::var_os(CONFIG_VARIABLE)
+            .map(PathBuf::from)
+            .or_else(|| home_dir().map(|home| home.join(".config/aoc/config.toml")))
+        else {
+            return Ok(Config::default());
+        };
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let content = fs::read_to_string(&path).map_err(|error| io_error(&path, error))?;
+        toml::from_str(&content).map_err(|error| FetchError::Config {
+            path,
+            message: error.to_string(),
+        })
+    }
+
+    /// The given base URL, else the one of `AOC_BASE_URL`, of the config file, or of Advent
+    /// of Code.
+    pub fn base_url(&self, base_url: Option<String>) -> String {
+        base_url
+            .or_else(|| env::var(BASE_URL_VARIABLE).ok())
+            .or_else(|| self.base_url.clone())
+            .unwrap_or_else(|| String::from(DEFAULT_BASE_URL))
+    }
+
+    /// The session of `AOC_SESSION`, else the one of the config file.
+    pub fn session(&self) -> Option<String> {
+        env::var(SESSION_VARIABLE)
+            .ok()
+            .or_else(|| self.session.clone())
+    }
+}
+
+/// Directory name of a server, such as `adventofcode.com` or `127.0.0.1_8080`, so that what is
+/// kept for a stand-in is never used with another server.
+pub(crate) fn endpoint_directory(base_url: &str) -> String {
+    let address = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, address)| address);
+    address
+        .trim_end_matches('/')
+        .chars()
+        .map(|char| {
+            if char.is_ascii_alphanumeric() || char == '.' || char == '-' {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// `$XDG_CACHE_HOME/aoc`, falling back on `~/.cache/aoc`.
+fn cache_dir() -> PathBuf {
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".cache")))
+        .unwrap_or_else(env::temp_dir)
+        .join("aoc")
+}
+
+/// `$XDG_DATA_HOME/aoc`, falling back on `~/.local/share/aoc`.
+pub(crate) fn data_dir() -> PathBuf {
+    env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".local/share")))
+        .unwrap_or_else(env::temp_dir)
+        .join("aoc")
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME").map(PathBuf::from)
+}
+
+pub(crate) fn write(path: &Path, content: &str) -> Result<(), FetchError> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(|error| io_error(directory, error))?;
+    }
+    fs::write(path, content).map_err(|error| io_error(path, error))
+}
+
+pub(crate) fn io_error(path: &Path, error: io::Error) -> FetchError {
+    FetchError::Io {
+        path: path.to_path_buf(),
+        error,
+    }
+}
+
+impl Display for Fetched {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fetched::AlreadyPresent => write!(f, "already present"),
+            Fetched::Cache => write!(f, "copied from the cache"),
+            Fetched::Download => write!(f, "downloaded"),
+        }
+    }
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Config { path, message } => {
+                write!(f, "Invalid config {}: {message}", path.display())
+            }
+            FetchError::MissingSession => write!(
+                f,
+                "No session token, set {SESSION_VARIABLE} or `session` in the config file"
+            ),
+            FetchError::Http { url, message } => write!(f, "Request to {url} failed: {message}"),
+            FetchError::Io { path, error } => {
+                write!(f, "Could not access {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scaffold::create_day;
+    use crate::stand_in::{serve, temporary_dir};
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let (base_url, requests) = serve("abcdef\n");
+        let directory = temporary_dir("fetch-once");
+        let fetcher = Fetcher {
+            base_url,
+            session: Some(String::from("token")),
+            cache_dir: directory.join("cache"),
+        };
+        let destination = directory.join("2015/day04/real.txt");
+
+        assert_eq!(
+            fetcher.fetch(2015, 4, &destination).unwrap(),
+            Fetched::Download
+        );
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "abcdef\n");
+        assert_eq!(
+            fetcher.fetch(2015, 4, &destination).unwrap(),
+            Fetched::AlreadyPresent
+        );
+
+        fs::remove_file(&destination).unwrap();
+        assert_eq!(
+            fetcher.fetch(2015, 4, &destination).unwrap(),
+            Fetched::Cache
+        );
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "abcdef\n");
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec!["GET /2015/day/4/input HTTP/1.1\nCookie: session=token"]
+        );
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_caches_by_endpoint() {
+        let directory = temporary_dir("fetch-endpoint");
+        let fetcher = |base_url: String| Fetcher {
+            base_url,
+            session: Some(String::from("token")),
+            cache_dir: directory.join("cache"),
+        };
+        let destination = directory.join("2015/day04/real.txt");
+
+        let (stand_in_url, _) = serve("stand-in\n");
+        fetcher(stand_in_url).fetch(2015, 4, &destination).unwrap();
+        fs::remove_file(&destination).unwrap();
+
+        let (other_url, requests) = serve("other\n");
+        assert_eq!(
+            fetcher(other_url).fetch(2015, 4, &destination).unwrap(),
+            Fetched::Download
+        );
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "other\n");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_endpoint_directory() {
+        assert_eq!(endpoint_directory(DEFAULT_BASE_URL), "adventofcode.com");
+        assert_eq!(
+            endpoint_directory("http://127.0.0.1:8080/aoc/"),
+            "127.0.0.1_8080_aoc"
+        );
+    }
+
+    #[test]
+    fn test_fetch_after_scaffold() {
+        let (base_url, requests) = serve("abcdef\n");
+        let root = temporary_dir("fetch-scaffold");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\n]\n").unwrap();
+        let fetcher = Fetcher {
+            base_url,
+            session: Some(String::from("token")),
+            cache_dir: root.join("cache"),
+        };
+
+        let directory = create_day(&root, 2015, 4, false).unwrap();
+        let destination = directory.join("real.txt");
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "");
+
+        assert_eq!(
+            fetcher.fetch(2015, 4, &destination).unwrap(),
+            Fetched::Download
+        );
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "abcdef\n");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_without_session() {
+        let directory = temporary_dir("fetch-session");
+        let fetcher = Fetcher {
+            base_url: String::from("http://127.0.0.1:9"),
+            session: None,
+            cache_dir: directory.join("cache"),
+        };
+
+        assert!(matches!(
+            fetcher.fetch(2022, 1, &directory.join("real.txt")),
+            Err(FetchError::MissingSession)
+        ));
+        assert!(!directory.exists());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod bench;
+pub mod days;
+pub mod fetch;
+pub mod parallel;
+pub mod scaffold;
+#[cfg(test)]
+mod stand_in;
+pub mod stepper;
+pub mod submit;
@@ -0,0 +1,642 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use aoc::{
+    bench::{self, Benchmark},
+    days::{self, Day, Implementation},
+    fetch::Fetcher,
+    parallel::{self, Outcome, Row, Status},
+    scaffold,
+    stepper::StepError,
+    submit::{Submitter, Verdict},
+};
+use aoc_core::{progress, Input};
+use clap::{ArgAction, Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Logs the diagnostics of the solutions to stderr, `-vv` for every detail
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Hides the progress bars of long loops
+    #[arg(long, global = true)]
+    no_progress: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs a single day, or every day of the year with `--all` or `--parallel`
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Runs every day of the year on a pool of workers, and prints a table of the results
+        #[arg(long, conflicts_with_all = ["day", "input", "input_string"])]
+        parallel: bool,
+        /// Workers of `--parallel`, one by CPU by default
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+        /// Seconds after which a day run by `--parallel` is reported as out of time
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Times the parsing and both parts of a single day, or of every day of the year with `--all`
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// Runs before measuring, whose timings are discarded
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        /// Measured runs
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Writes the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Writes the results as CSV to this file
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+    /// Replays the simulation of a day, as a GIF or as its last frame
+    Render {
+        #[command(flatten)]
+        simulation: SimulationSelection,
+        /// File to write, as GIF, PNG or PPM depending on its extension
+        output: PathBuf,
+    },
+    /// Steps through the simulation of a day in the terminal
+    Step {
+        #[command(flatten)]
+        simulation: SimulationSelection,
+    },
+    /// Lists every day and how it is solved
+    List { year: Option<u16> },
+    /// Downloads the input of a day to its `real.txt`, once: later fetches use the cache
+    Fetch {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Server to download from, instead of `AOC_BASE_URL`, the config file or Advent of Code
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Creates the crate of a new day, and adds it to the workspace
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Creates the crate even if the directory of the day already exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Submits the answer of a part, computed from the `real.txt` of the day unless given
+    Submit {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to submit instead of the computed one
+        #[arg(long)]
+        answer: Option<String>,
+        /// Server to submit to, instead of `AOC_BASE_URL`, the config file or Advent of Code
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+}
+
+/// Days to run, picked from the command line.
+#[derive(Args)]
+struct Selection {
+    #[arg(required_unless_present = "year_option")]
+    year: Option<u16>,
+    /// Year, as an option, for every day of the year with `--all` or `--parallel`
+    #[arg(long = "year", value_name = "YEAR", conflicts_with = "year")]
+    year_option: Option<u16>,
+    day: Option<u8>,
+    /// Runs every day of the year
+    #[arg(long, conflicts_with_all = ["day", "input", "input_string"])]
+    all: bool,
+    /// Input file, looked up from the day directory when relative and not found, or `-` to
+    /// read stdin
+    #[arg(long)]
+    input: Option<String>,
+    /// Input given inline instead of in a file
+    #[arg(long, conflicts_with = "input")]
+    input_string: Option<String>,
+}
+
+/// Day whose simulation is replayed, picked from the command line.
+#[derive(Args)]
+struct SimulationSelection {
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Input file, looked up from the day directory when relative and not found, or `-` to
+    /// read stdin
+    #[arg(long)]
+    input: Option<String>,
+    /// Input given inline instead of in a file
+    #[arg(long, conflicts_with = "input")]
+    input_string: Option<String>,
+}
+
+impl Selection {
+    /// Selected days, or `None` after reporting that there is no such day.
+    fn days(&self) -> Option<Vec<&'static Day>> {
+        let Selection { day, all, .. } = *self;
+        let year = self.year();
+
+        let selected_days: Vec<&Day> = match day {
+            Some(day) => days::find(year, day).into_iter().collect(),
+            None if all => days::of_year(year).collect(),
+            None => {
+                eprintln!("{year}: a day, or `--all` for every day of the year, is needed");
+                return None;
+            }
+        };
+
+        if selected_days.is_empty() {
+            match day {
+                Some(day) => eprintln!("{year} day {day:02}: no such day"),
+                None => eprintln!("{year}: no such year"),
+            }
+            return None;
+        }
+        Some(selected_days)
+    }
+
+    fn year(&self) -> u16 {
+        self.year
+            .or(self.year_option)
+            .expect("clap requires a year")
+    }
+
+    /// Input of a selected day.
+    fn input(&self, day: &Day) -> Input {
+        match &self.input_string {
+            Some(content) => Input::from_string(content),
+            None => Input::resolve(self.input.as_deref(), &day.directory()),
+        }
+    }
+}
+
+impl SimulationSelection {
+    fn label(&self) -> String {
+        format!("{} day {:02}", self.year, self.day)
+    }
+
+    /// Replay of the selected day, `what` it is, or `None` after reporting the days having
+    /// one.
+    fn find<T>(&self, replay: impl Fn(&Day) -> Option<T>, what: &str) -> Option<T> {
+        let found = days::find(self.year, self.day).and_then(&replay);
+        if found.is_none() {
+            let replayed_days: Vec<String> = days::DAYS
+                .iter()
+                .filter(|day| replay(day).is_some())
+                .map(|day| format!("{} day {:02}", day.year, day.day))
+                .collect();
+            eprintln!(
+                "{}: no {what}, only for {}",
+                self.label(),
+                replayed_days.join(", ")
+            );
+        }
+        found
+    }
+
+    fn input(&self) -> Input {
+        match &self.input_string {
+            Some(content) => Input::from_string(content),
+            None => Input::resolve(self.input.as_deref(), &days::directory(self.year, self.day)),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_core::logging::init(cli.verbose);
+    progress::set_enabled(!cli.no_progress);
+
+    match cli.command {
+        Command::Run {
+            mut selection,
+            parallel,
+            jobs,
+            timeout,
+        } => {
+            selection.all |= parallel;
+            let Some(selected_days) = selection.days() else {
+                return ExitCode::FAILURE;
+            };
+            if parallel {
+                let workers = jobs.map_or_else(
+                    || thread::available_parallelism().map_or(1, |workers| workers.get()),
+                    |jobs| jobs as usize,
+                );
+                return run_parallel(&selected_days, workers, Duration::from_secs(timeout));
+            }
+            let answered: Vec<Option<bool>> = selected_days
+                .into_iter()
+                .map(|day| run_day(day, &selection))
+                .collect();
+
+            // The days not solved in Rust only fail when asked for by themselves.
+            if answered
+                .into_iter()
+                .all(|answered| answered.unwrap_or(selection.all))
+            {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Bench {
+            selection,
+            warmup,
+            iterations,
+            json,
+            csv,
+        } => {
+            let Some(selected_days) = selection.days() else {
+                return ExitCode::FAILURE;
+            };
+            let options = bench::Options {
+                warmup,
+                iterations: iterations as usize,
+            };
+            // Bars would be redrawn by every run, and slow down the measures.
+            progress::set_enabled(false);
+            let benchmarks: Vec<Benchmark> = selected_days
+                .into_iter()
+                .filter_map(|day| bench_day(day, &selection, options))
+                .collect();
+
+            let exported = write_export(json.as_deref(), || bench::to_json(&benchmarks))
+                && write_export(csv.as_deref(), || bench::to_csv(&benchmarks));
+
+            if exported && (selection.all || !benchmarks.is_empty()) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Render { simulation, output } => {
+            let label = simulation.label();
+            let Some(animate) = simulation.find(|day| day.animator, "animation") else {
+                return ExitCode::FAILURE;
+            };
+
+            let input = simulation.input();
+            let animation = match animate(input.as_str()) {
+                Ok(animation) => animation,
+                Err(error) => {
+                    eprintln!("{label}\n{}", error.with_file(input.path()));
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            match animation.save(&output) {
+                Ok(()) => {
+                    println!(
+                        "{label}: {} ({} frames)",
+                        output.display(),
+                        animation.frames().len()
+                    );
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("{label}: could not write {}: {error}", output.display());
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Step { simulation } => {
+            let label = simulation.label();
+            let Some(step) = simulation.find(|day| day.stepper, "stepper") else {
+                return ExitCode::FAILURE;
+            };
+
+            let input = simulation.input();
+            match step(input.as_str()) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(StepError::Parse(error)) => {
+                    eprintln!("{label}\n{}", error.with_file(input.path()));
+                    ExitCode::FAILURE
+                }
+                Err(error) => {
+                    eprintln!("{label}: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::List { year } => {
+            let mut stdout = io::stdout().lock();
+            for day in days::DAYS
+                .iter()
+                .filter(|day| year.is_none_or(|year| day.year == year))
+            {
+                // Stops quietly once the reader is gone, such as `head`.
+                if writeln!(stdout, "{}", list_line(day)).is_err() {
+                    break;
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Fetch {
+            year,
+            day,
+            base_url,
+        } => {
+            let destination = days::directory(year, day).join("real.txt");
+            let fetched = Fetcher::from_environment(base_url)
+                .and_then(|fetcher| fetcher.fetch(year, day, &destination));
+
+            match fetched {
+                Ok(fetched) => {
+                    println!("{year} day {day:02}: {} ({fetched})", destination.display());
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("{year} day {day:02}: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::New { year, day, force } => {
+            match scaffold::create_day(&days::workspace_root(), year, day, force) {
+                Ok(directory) => {
+                    println!(
+                        "{year} day {day:02}: created {}\n\
+                        To run it with `aoc`, add it to the dependencies of `aoc/Cargo.toml` \
+                        and to `DAYS` in `aoc/src/days.rs`.",
+                        directory.display()
+                    );
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("{year} day {day:02}: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            base_url,
+        } => {
+            let label = format!("{year} day {day:02} part {part}");
+            let Some(answer) = answer.or_else(|| compute_answer(year, day, part, &label)) else {
+                return ExitCode::FAILURE;
+            };
+
+            let submitted = Submitter::from_environment(base_url)
+                .and_then(|submitter| submitter.submit(year, day, part, &answer));
+            match submitted {
+                Ok((verdict, message)) => {
+                    println!("{label}: {answer} is {verdict}\n{message}");
+                    if verdict == Verdict::Correct {
+                        ExitCode::SUCCESS
+                    } else {
+                        ExitCode::FAILURE
+                    }
+                }
+                Err(error) => {
+                    eprintln!("{label}: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+/// Answer of a part on the `real.txt` of the day, or `None` after reporting why there is none.
+fn compute_answer(year: u16, day: u8, part: u8, label: &str) -> Option<String> {
+    let Some(Day {
+        implementation: Implementation::Rust(solve),
+        ..
+    }) = days::find(year, day)
+    else {
+        eprintln!("{label}: not solved in Rust, give the answer with `--answer`");
+        return None;
+    };
+
+    let path = days::directory(year, day).join("real.txt");
+    if !path.exists() {
+        eprintln!(
+            "{label}: no {}, run `aoc fetch {year} {day}`",
+            path.display()
+        );
+        return None;
+    }
+
+    let input = Input::from_path(&path);
+    let answers = match solve(input.as_str()) {
+        Ok((answers, _)) => answers,
+        Err(error) => {
+            eprintln!("{label}\n{}", error.with_file(input.path()));
+            return None;
+        }
+    };
+
+    let answer = if part == 1 {
+        answers.part1
+    } else {
+        answers.part2
+    };
+    if answer.contains('\n') {
+        eprintln!("{label}: the answer is drawn, read it and give it with `--answer`\n{answer}");
+        return None;
+    }
+    Some(answer)
+}
+
+/// Runs a day and prints its answer, returning whether an answer was found, or `None` if the
+/// day is not solved in Rust.
+fn run_day(day: &Day, selection: &Selection) -> Option<bool> {
+    let label = format!("{} day {:02}", day.year, day.day);
+
+    match day.implementation {
+        Implementation::Rust(solve) => {
+            let input = selection.input(day);
+            match solve(input.as_str()) {
+                Ok((answers, _)) => {
+                    println!("{label}\n{answers}");
+                    for report in progress::take_reports() {
+                        eprintln!("  {report}");
+                    }
+                    Some(true)
+                }
+                Err(error) => {
+                    eprintln!("{label}\n{}", error.with_file(input.path()));
+                    progress::take_reports();
+                    Some(false)
+                }
+            }
+        }
+        _ => {
+            print_skipped(day, &label);
+            None
+        }
+    }
+}
+
+/// Runs the days with their default input on a pool of workers, and prints the table of their
+/// results. Fails if a day could not be answered.
+fn run_parallel(selected_days: &[&Day], workers: usize, timeout: Duration) -> ExitCode {
+    // Bars of several days at once would be drawn over each other.
+    progress::set_enabled(false);
+
+    let solved_days: Vec<&Day> = selected_days
+        .iter()
+        .copied()
+        .filter(|day| matches!(day.implementation, Implementation::Rust(_)))
+        .collect();
+    let tasks: Vec<_> = solved_days
+        .iter()
+        .map(|day| {
+            let Implementation::Rust(solve) = day.implementation else {
+                unreachable!("only the days solved in Rust are run");
+            };
+            let directory = day.directory();
+            let task = move || {
+                let input = Input::resolve(None, &directory);
+                solve(input.as_str())
+                    .map(|(answers, _)| answers)
+                    .map_err(|error| error.with_file(input.path()).to_string())
+            };
+            (format!("{} day {:02}", day.year, day.day), task)
+        })
+        .collect();
+    let mut finished = parallel::run_pool(tasks, workers, timeout).into_iter();
+    // Loops of the days are reported by the threads running them, named after the days.
+    let reports = progress::take_reports();
+
+    let mut is_answered = true;
+    let rows: Vec<Row> = selected_days
+        .iter()
+        .map(|day| {
+            let label = format!("{} day {:02}", day.year, day.day);
+            let Implementation::Rust(_) = day.implementation else {
+                let reason = match day.implementation {
+                    Implementation::Other(language, _) => format!("solved in {language}"),
+                    _ => String::from("not solved"),
+                };
+                return Row {
+                    label,
+                    status: Status::Skipped(reason),
+                    elapsed: None,
+                };
+            };
+
+            let task_finished = finished.next().expect("every solved day is run");
+            let status = match task_finished.outcome {
+                Outcome::Done(Ok(answers)) => Status::Ok(answers),
+                Outcome::Done(Err(error)) => Status::Error(error),
+                Outcome::Panic(message) => Status::Panic(message),
+                Outcome::Timeout => Status::Timeout,
+            };
+            is_answered &= matches!(status, Status::Ok(_));
+            Row {
+                label,
+                status,
+                elapsed: Some(task_finished.elapsed),
+            }
+        })
+        .collect();
+
+    println!("{}", parallel::table(&rows));
+    for row in &rows {
+        for report in reports
+            .iter()
+            .filter(|report| report.thread.as_deref() == Some(row.label.as_str()))
+        {
+            eprintln!("  {} {report}", row.label);
+        }
+    }
+    if is_answered {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Benchmarks a day and prints its report, returning `None` when it cannot be run.
+fn bench_day(day: &Day, selection: &Selection, options: bench::Options) -> Option<Benchmark> {
+    let Implementation::Rust(solve) = day.implementation else {
+        print_skipped(day, &format!("{} day {:02}", day.year, day.day));
+        return None;
+    };
+
+    let input = selection.input(day);
+    let measures = bench::measure(solve, input.as_str(), options);
+    progress::take_reports();
+    let [parse, part1, part2] = match measures {
+        Ok(statistics) => statistics,
+        Err(error) => {
+            eprintln!(
+                "{} day {:02}\n{}",
+                day.year,
+                day.day,
+                error.with_file(input.path())
+            );
+            return None;
+        }
+    };
+    let benchmark = Benchmark {
+        year: day.year,
+        day: day.day,
+        input: input.path().file_name().map_or_else(
+            || input.path().display().to_string(),
+            |file_name| file_name.to_string_lossy().into_owned(),
+        ),
+        iterations: options.iterations,
+        parse,
+        part1,
+        part2,
+    };
+
+    println!("{}", benchmark.report());
+    Some(benchmark)
+}
+
+/// Writes an export when its path is given, returning whether nothing failed.
+fn write_export(path: Option<&Path>, export: impl FnOnce() -> String) -> bool {
+    let Some(path) = path else {
+        return true;
+    };
+
+    fs::write(path, export())
+        .map_err(|error| eprintln!("Could not write {}: {error}", path.display()))
+        .is_ok()
+}
+
+fn print_skipped(day: &Day, label: &str) {
+    match day.implementation {
+        Implementation::Other(language, file_name) => println!(
+            "{label}: skipped, solved in {language} ({}/day{:02}/{file_name})",
+            day.year, day.day
+        ),
+        _ => println!("{label}: skipped, not solved"),
+    }
+}
+
+fn list_line(day: &Day) -> String {
+    let status = match day.implementation {
+        Implementation::Rust(_) => String::from("Rust"),
+        Implementation::Other(language, file_name) => format!("{language} ({file_name})"),
+        Implementation::Missing => String::from("not solved"),
+    };
+
+    format!("{} day {:02}: {status}", day.year, day.day)
+}
@@ -0,0 +1,275 @@
+//! Runs days on a pool of worker threads, a day panicking or running out of time being
+//! reported without stopping the others, and summarizes them in a table.
+
+use std::{
+    any::Any,
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Answers;
+
+/// How a task of the pool ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Done(T),
+    /// Panicked, with the message of the panic.
+    Panic(String),
+    Timeout,
+}
+
+/// Task of the pool once ended, with the wall time it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finished<T> {
+    pub outcome: Outcome<T>,
+    pub elapsed: Duration,
+}
+
+/// Result of a day, as shown in the summary table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok(Answers),
+    /// Malformed input, with the parse error.
+    Error(String),
+    Panic(String),
+    Timeout,
+    /// Not run, with the reason.
+    Skipped(String),
+}
+
+/// Line of the summary table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub label: String,
+    pub status: Status,
+    pub elapsed: Option<Duration>,
+}
+
+/// Runs the named tasks on `workers` threads, returning how each one ended, in the order of the
+/// tasks.
+///
+/// Each task runs on a thread of its own, named after the task, watched by a worker which moves
+/// on to the next task once it ends or after `timeout`. A thread cannot be stopped, so a task
+/// out of time keeps running in the background until the end of the process.
+pub fn run_pool<T, F>(
+    tasks: Vec<(String, F)>,
+    workers: usize,
+    timeout: Duration,
+) -> Vec<Finished<T>>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let task_count = tasks.len();
+    let queue = Mutex::new(tasks.into_iter().enumerate().collect::<VecDeque<_>>());
+    let finished: Mutex<Vec<Option<Finished<T>>>> =
+        Mutex::new((0..task_count).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, task_count.max(1)) {
+            scope.spawn(|| loop {
+                let Some((index, (name, task))) = queue.lock().unwrap().pop_front() else {
+                    break;
+                };
+                let task_finished = watch(name, task, timeout);
+                finished.lock().unwrap()[index] = Some(task_finished);
+            });
+        }
+    });
+
+    finished
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|task_finished| task_finished.expect("every task is run by a worker"))
+        .collect()
+}
+
+/// Runs a task on a new thread named `name`, waiting for it at most `timeout`.
+fn watch<T, F>(name: String, task: F, timeout: Duration) -> Finished<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new().name(name).spawn(move || {
+        // The watcher is gone once the task is out of time.
+        let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(task)));
+    });
+    if let Err(error) = spawned {
+        return Finished {
+            outcome: Outcome::Panic(format!("the thread of the task could not start: {error}")),
+            elapsed: start.elapsed(),
+        };
+    }
+
+    let outcome = match receiver.recv_timeout(timeout) {
+        Ok(Ok(value)) => Outcome::Done(value),
+        Ok(Err(payload)) => Outcome::Panic(panic_message(payload.as_ref())),
+        Err(mpsc::RecvTimeoutError::Timeout) => Outcome::Timeout,
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Outcome::Panic(String::from("the thread of the task stopped"))
+        }
+    };
+
+    Finished {
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Message given to `panic!`, which is a `&str` or a `String` unless raised with
+/// `panic_any`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(no message)"))
+}
+
+/// Table of the days, with their status, their wall time and their answers. Cells spanning
+/// several lines, such as answers drawn as letters or parse errors, are written below the
+/// table.
+pub fn table(rows: &[Row]) -> String {
+    const HEADER: [&str; 5] = ["day", "status", "time", "part 1", "part 2"];
+
+    let mut details: Vec<String> = Vec::new();
+    let mut lines: Vec<[String; 5]> = vec![HEADER.map(String::from)];
+    for row in rows {
+        let (status, part1, part2) = match &row.status {
+            Status::Ok(answers) => ("ok", answers.part1.as_str(), answers.part2.as_str()),
+            Status::Error(error) => ("error", error.as_str(), ""),
+            Status::Panic(message) => ("panic", message.as_str(), ""),
+            Status::Timeout => ("timeout", "", ""),
+            Status::Skipped(reason) => ("skipped", reason.as_str(), ""),
+        };
+        let mut cell = |name: &str, content: &str| {
+            if content.contains('\n') {
+                details.push(format!("{} {name}:\n{}", row.label, content.trim_end()));
+                String::from("(below)")
+            } else {
+                content.to_string()
+            }
+        };
+        let (part1, part2) = match row.status {
+            Status::Ok(_) => (cell("part 1", part1), cell("part 2", part2)),
+            _ => (cell(status, part1), String::new()),
+        };
+
+        lines.push([
+            row.label.clone(),
+            status.to_string(),
+            row.elapsed
+                .map_or_else(String::new, |elapsed| format!("{elapsed:.2?}")),
+            part1,
+            part2,
+        ]);
+    }
+
+    let mut widths = [0; 5];
+    for line in &lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table: Vec<String> = lines
+        .iter()
+        .map(|line| {
+            let cells: Vec<String> = line
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        })
+        .collect();
+    for detail in details {
+        table.push(String::new());
+        table.push(detail);
+    }
+    table.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_pool() {
+        let tasks: Vec<Box<dyn FnOnce() -> u32 + Send>> = vec![
+            Box::new(|| 1),
+            Box::new(|| panic!("no solution")),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(60));
+                3
+            }),
+            // Runs on a thread named after the task.
+            Box::new(|| match thread::current().name() {
+                Some("task 3") => 4,
+                _ => 0,
+            }),
+        ];
+        let tasks = tasks
+            .into_iter()
+            .enumerate()
+            .map(|(index, task)| (format!("task {index}"), task))
+            .collect();
+
+        // Long enough for the panic hook to print a backtrace.
+        let outcomes: Vec<Outcome<u32>> = run_pool(tasks, 2, Duration::from_secs(2))
+            .into_iter()
+            .map(|task_finished| task_finished.outcome)
+            .collect();
+        assert_eq!(
+            outcomes,
+            [
+                Outcome::Done(1),
+                Outcome::Panic(String::from("no solution")),
+                Outcome::Timeout,
+                Outcome::Done(4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let rows = [
+            Row {
+                label: String::from("2022 day 01"),
+                status: Status::Ok(Answers {
+                    part1: String::from("24000"),
+                    part2: String::from("45000"),
+                }),
+                elapsed: Some(Duration::from_micros(1500)),
+            },
+            Row {
+                label: String::from("2022 day 02"),
+                status: Status::Timeout,
+                elapsed: Some(Duration::from_secs(60)),
+            },
+            Row {
+                label: String::from("2022 day 03"),
+                status: Status::Error(String::from("line 1\n^ expected a letter")),
+                elapsed: Some(Duration::from_millis(2)),
+            },
+        ];
+
+        assert_eq!(
+            table(&rows),
+            "day          status   time    part 1   part 2\n\
+             2022 day 01  ok       1.50ms  24000    45000\n\
+             2022 day 02  timeout  60.00s\n\
+             2022 day 03  error    2.00ms  (below)\n\
+             \n\
+             2022 day 03 error:\n\
+             line 1\n\
+             ^ expected a letter"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tpl");
+
+/// Empty input files created with a new day.
+const INPUT_FILES: [&str; 2] = ["example.txt", "real.txt"];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    /// The directory of the day exists without a crate, such as one holding another solution.
+    DirectoryExists(PathBuf),
+    /// The root manifest has no `members = [` list to insert the day into.
+    NoMembers(PathBuf),
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+/// Creates the crate of a day in the workspace living in `root`, and adds it to the members
+/// of the workspace. Returns the directory of the new crate. An existing directory is only
+/// filled when `force` is set, and never when it already holds a crate.
+pub fn create_day(root: &Path, year: u16, day: u8, force: bool) -> Result<PathBuf, ScaffoldError> {
+    let member = format!("{year}/day{day:02}");
+    let directory = root.join(&member);
+    if directory.join("Cargo.toml").exists() {
+        return Err(ScaffoldError::AlreadyExists(directory));
+    }
+    if directory.exists() && !force {
+        return Err(ScaffoldError::DirectoryExists(directory));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = read(&manifest_path)?;
+    let manifest = insert_member(&manifest, &member)
+        .ok_or_else(|| ScaffoldError::NoMembers(manifest_path.clone()))?;
+
+    let crate_name = format!("day{day:02}-{year}");
+    let fill = |template: &str| {
+        template
+            .replace("{crate_name}", &crate_name)
+            .replace("{crate_ident}", &crate_name.replace('-', "_"))
+    };
+
+    write(&directory.join("Cargo.toml"), &fill(CARGO_TEMPLATE))?;
+    write(&directory.join("src/main.rs"), &fill(MAIN_TEMPLATE))?;
+    write(&directory.join("src/lib.rs"), &fill(LIB_TEMPLATE))?;
+    for file_name in INPUT_FILES {
+        let path = directory.join(file_name);
+        if !path.exists() {
+            write(&path, "")?;
+        }
+    }
+    write(&manifest_path, &manifest)?;
+
+    Ok(directory)
+}
+
+/// Adds `member` to the `members` list of a workspace manifest, keeping the list sorted.
+/// Returns `None` when the manifest has no such list.
+pub fn insert_member(manifest: &str, member: &str) -> Option<String> {
+    let lines: Vec<&str> = manifest.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "]")?;
+
+    let entry = format!("    \"{member}\",");
+    let members = &lines[start + 1..end];
+    if members.iter().any(|line| line.trim() == entry.trim()) {
+        return Some(String::from(manifest));
+    }
+    let index = start
+        + 1
+        + members
+            .iter()
+            .position(|line| line.trim() > entry.trim())
+            .unwrap_or(members.len());
+
+    let mut new_lines: Vec<&str> = lines.clone();
+    new_lines.insert(index, &entry);
+    let mut new_manifest = new_lines.join("\n");
+    if manifest.ends_with('\n') {
+        new_manifest.push('\n');
+    }
+    Some(new_manifest)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    let io_error = |error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(io_error)?;
+    }
+    fs::write(path, content).map_err(io_error)
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(directory) => {
+                write!(f, "{} already holds a crate", directory.display())
+            }
+            ScaffoldError::DirectoryExists(directory) => write!(
+                f,
+                "{} already exists, use --force to create the crate in it",
+                directory.display()
+            ),
+            ScaffoldError::NoMembers(path) => {
+                write!(f, "No `members = [` list in {}", path.display())
+            }
+            ScaffoldError::Io { path, error } => {
+                write!(f, "Could not access {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::temporary_dir;
+
+    const MANIFEST: &str =
+        "[workspace]\nmembers = [\n    \"2015/day01\",\n    \"2022/day24\",\n    \"aoc\",\n]\n";
+
+    #[test]
+    fn test_insert_member() {
+        assert_eq!(
+            insert_member(MANIFEST, "2022/day23").unwrap(),
+            "[workspace]\nmembers = [\n    \"2015/day01\",\n    \"2022/day23\",\n    \"2022/day24\",\n    \"aoc\",\n]\n"
+        );
+        assert_eq!(insert_member(MANIFEST, "2022/day24").unwrap(), MANIFEST);
+        assert_eq!(insert_member("[package]\n", "2022/day23"), None);
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = temporary_dir("scaffold");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        let directory = create_day(&root, 2022, 23, false).unwrap();
+
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"2022/day23\","));
+        assert!(fs::read_to_string(directory.join("Cargo.toml"))
+            .unwrap()
+            .contains("name = \"day23-2022\""));
+        assert!(fs::read_to_string(directory.join("src/main.rs"))
+            .unwrap()
+            .contains("aoc_core::run::<day23_2022::Day>"));
+        assert_eq!(fs::read_to_string(directory.join("real.txt")).unwrap(), "");
+        assert!(matches!(
+            create_day(&root, 2022, 23, true),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_create_day_in_existing_directory() {
+        let root = temporary_dir("scaffold-existing");
+        fs::create_dir_all(root.join("2022/day23")).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("2022/day23/solution.py"), "print(42)\n").unwrap();
+
+        assert!(matches!(
+            create_day(&root, 2022, 23, false),
+            Err(ScaffoldError::DirectoryExists(_))
+        ));
+        assert!(!root.join("2022/day23/src").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            MANIFEST
+        );
+
+        let directory = create_day(&root, 2022, 23, true).unwrap();
+        assert!(directory.join("src/lib.rs").exists());
+        assert_eq!(
+            fs::read_to_string(directory.join("solution.py")).unwrap(),
+            "print(42)\n"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
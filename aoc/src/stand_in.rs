@@ -0,0 +1,66 @@
+//! Local stand-in for the puzzle server, for the tests of the HTTP clients.
+
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    process,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// Serves `body` to every request, and returns the base URL of the server with the requests
+/// it received: their request line, cookie and body.
+pub fn serve(body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+
+            let mut request = Vec::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(length) = line.strip_prefix("Content-Length: ") {
+                    content_length = length.parse().unwrap();
+                }
+                if request.is_empty() || line.starts_with("Cookie") {
+                    request.push(String::from(line));
+                }
+            }
+            if content_length > 0 {
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push(format!("\n{}", String::from_utf8(request_body).unwrap()));
+            }
+            received.lock().unwrap().push(request.join("\n"));
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
+
+/// Empty directory for a test, which is not created.
+pub fn temporary_dir(name: &str) -> PathBuf {
+    let directory = env::temp_dir().join(format!("aoc-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    directory
+}
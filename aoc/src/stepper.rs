@@ -0,0 +1,311 @@
+//! Terminal stepper, drawing the state of a simulation and moving through its ticks with the
+//! keyboard.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, Stdout, Write},
+    time::Duration,
+};
+
+use aoc_core::{
+    simulation::{Simulate, Simulation, Timeline},
+    ParseError,
+};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, Clear, ClearType},
+};
+
+/// Time between two ticks while playing.
+const PLAY_INTERVAL: Duration = Duration::from_millis(50);
+/// Cells the cursor moves by when shift is held.
+const FAST_CURSOR_MOVE: isize = 10;
+/// Lines below the grid, for the status and the controls.
+const STATUS_HEIGHT: u16 = 2;
+const HELP: &str =
+    "n: next  b: back  <count>n/b: jump  space: play/pause  arrows: move cursor  q: quit";
+
+#[derive(Debug)]
+pub enum StepError {
+    Parse(ParseError),
+    Terminal(io::Error),
+}
+
+/// What a key does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Forward(usize),
+    Back(usize),
+    TogglePlay,
+    MoveCursor(isize, isize),
+    Quit,
+}
+
+/// Keys typed so far, a count before a jump.
+#[derive(Debug, Default)]
+struct Controls {
+    count: Option<usize>,
+}
+
+/// Simulation being stepped through, with the cursor and the part of the grid shown.
+struct Stepper<S> {
+    timeline: Timeline<S>,
+    controls: Controls,
+    is_playing: bool,
+    cursor: (usize, usize),
+    /// Cell of the grid in the top left corner of the terminal.
+    origin: (usize, usize),
+}
+
+/// Terminal in raw mode on the alternate screen, restored when dropped.
+struct Screen {
+    stdout: Stdout,
+}
+
+/// Steps through the simulation of a day, parsed from `input`, until `q` is pressed.
+pub fn step<S: Simulate>(input: &str) -> Result<(), StepError> {
+    let input = S::parse(input).map_err(StepError::Parse)?;
+    let mut stepper = Stepper {
+        timeline: Timeline::new(S::simulate(&input)),
+        controls: Controls::default(),
+        is_playing: false,
+        cursor: (0, 0),
+        origin: (0, 0),
+    };
+
+    let mut screen = Screen::enter()?;
+    stepper.run(&mut screen.stdout)?;
+    Ok(())
+}
+
+impl Controls {
+    /// Action of a key, if it completes one. Digits make up the count of ticks of the next
+    /// jump.
+    fn action(&mut self, key: KeyEvent) -> Option<Action> {
+        let count = self.count.take();
+        let fast = if key.modifiers.contains(KeyModifiers::SHIFT) {
+            FAST_CURSOR_MOVE
+        } else {
+            1
+        };
+
+        match key.code {
+            KeyCode::Char(digit @ '0'..='9') => {
+                let digit = digit.to_digit(10).unwrap() as usize;
+                self.count = Some(count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+                None
+            }
+            KeyCode::Char('n' | '.') => Some(Action::Forward(count.unwrap_or(1))),
+            KeyCode::Char('b' | ',') => Some(Action::Back(count.unwrap_or(1))),
+            KeyCode::Char(' ' | 'p') => Some(Action::TogglePlay),
+            KeyCode::Left => Some(Action::MoveCursor(-fast, 0)),
+            KeyCode::Right => Some(Action::MoveCursor(fast, 0)),
+            KeyCode::Up => Some(Action::MoveCursor(0, -fast)),
+            KeyCode::Down => Some(Action::MoveCursor(0, fast)),
+            // Escape cancels a count being typed before quitting.
+            KeyCode::Esc if count.is_some() => None,
+            KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::Quit)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<S: Simulation> Stepper<S> {
+    fn run(&mut self, stdout: &mut Stdout) -> io::Result<()> {
+        loop {
+            self.draw(stdout)?;
+
+            if self.is_playing && !event::poll(PLAY_INTERVAL)? {
+                self.is_playing = self.timeline.forward(1) == 1;
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind == KeyEventKind::Release {
+                continue;
+            }
+
+            match self.controls.action(key) {
+                Some(Action::Forward(ticks)) => {
+                    self.timeline.forward(ticks);
+                }
+                Some(Action::Back(ticks)) => self.timeline.back(ticks),
+                Some(Action::TogglePlay) => {
+                    self.is_playing = !self.is_playing && !self.timeline.is_over();
+                }
+                Some(Action::MoveCursor(dx, dy)) => {
+                    self.cursor = (
+                        self.cursor.0.saturating_add_signed(dx),
+                        self.cursor.1.saturating_add_signed(dy),
+                    );
+                }
+                Some(Action::Quit) => return Ok(()),
+                None => {}
+            }
+        }
+    }
+
+    fn draw(&mut self, stdout: &mut Stdout) -> io::Result<()> {
+        let grid = self.timeline.current().render();
+        let (columns, rows) = terminal::size()?;
+        let (width, height) = (
+            columns as usize,
+            rows.saturating_sub(STATUS_HEIGHT) as usize,
+        );
+
+        // Keep the cursor on the grid, and the grid scrolled to show it.
+        self.cursor = (
+            self.cursor.0.min(grid.width().saturating_sub(1)),
+            self.cursor.1.min(grid.height().saturating_sub(1)),
+        );
+        self.origin = (
+            scroll(self.origin.0, self.cursor.0, width),
+            scroll(self.origin.1, self.cursor.1, height),
+        );
+
+        for row in 0..height {
+            queue!(stdout, cursor::MoveTo(0, row as u16))?;
+            let y = self.origin.1 + row;
+            if y < grid.height() {
+                let cells = &grid.row(y)[self.origin.0.min(grid.width())..];
+                for (column, &cell) in cells.iter().take(width).enumerate() {
+                    if (self.origin.0 + column, y) == self.cursor {
+                        queue!(
+                            stdout,
+                            SetAttribute(Attribute::Reverse),
+                            Print(cell),
+                            SetAttribute(Attribute::Reset)
+                        )?;
+                    } else {
+                        queue!(stdout, Print(cell))?;
+                    }
+                }
+            }
+            queue!(stdout, Clear(ClearType::UntilNewLine))?;
+        }
+
+        let status = self.status(&grid);
+        for (index, line) in [status.as_str(), HELP].into_iter().enumerate() {
+            queue!(
+                stdout,
+                cursor::MoveTo(0, (height + index) as u16),
+                Print(line.chars().take(width).collect::<String>()),
+                Clear(ClearType::UntilNewLine)
+            )?;
+        }
+        stdout.flush()
+    }
+
+    fn status(&self, grid: &aoc_core::Grid<char>) -> String {
+        let mut status = format!("tick {}", self.timeline.tick());
+        if self.timeline.is_over() {
+            status.push_str(" (over)");
+        } else if self.is_playing {
+            status.push_str(" (playing)");
+        }
+        if let Some(count) = self.controls.count {
+            status.push_str(&format!("  count {count}"));
+        }
+        if grid.contains(self.cursor) {
+            status.push_str(&format!(
+                "  {}",
+                self.timeline.current().inspect(self.cursor)
+            ));
+        }
+        status
+    }
+}
+
+/// First cell shown along an axis of `length` cells, moved as little as possible to show the
+/// cursor.
+fn scroll(origin: usize, cursor: usize, length: usize) -> usize {
+    if cursor < origin {
+        cursor
+    } else if cursor >= origin + length {
+        cursor + 1 - length.max(1)
+    } else {
+        origin
+    }
+}
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        let mut stdout = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen { stdout })
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(self.stdout, cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+impl From<io::Error> for StepError {
+    fn from(error: io::Error) -> StepError {
+        StepError::Terminal(error)
+    }
+}
+
+impl Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StepError::Parse(error) => write!(f, "{error}"),
+            StepError::Terminal(error) => write!(f, "could not use the terminal: {error}"),
+        }
+    }
+}
+
+impl Error for StepError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(controls: &mut Controls, code: KeyCode) -> Option<Action> {
+        controls.action(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_controls() {
+        let mut controls = Controls::default();
+
+        assert_eq!(
+            press(&mut controls, KeyCode::Char('n')),
+            Some(Action::Forward(1))
+        );
+        assert_eq!(press(&mut controls, KeyCode::Char('2')), None);
+        assert_eq!(press(&mut controls, KeyCode::Char('5')), None);
+        assert_eq!(controls.count, Some(25));
+        assert_eq!(
+            press(&mut controls, KeyCode::Char('b')),
+            Some(Action::Back(25))
+        );
+        assert_eq!(
+            controls.action(KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT)),
+            Some(Action::MoveCursor(0, -FAST_CURSOR_MOVE))
+        );
+
+        assert_eq!(press(&mut controls, KeyCode::Char('3')), None);
+        assert_eq!(press(&mut controls, KeyCode::Esc), None);
+        assert_eq!(press(&mut controls, KeyCode::Esc), Some(Action::Quit));
+    }
+
+    #[test]
+    fn test_scroll() {
+        assert_eq!(scroll(0, 5, 10), 0);
+        assert_eq!(scroll(0, 12, 10), 3);
+        assert_eq!(scroll(8, 4, 10), 4);
+    }
+}
@@ -0,0 +1,369 @@
+use std::{
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::{self, Config, FetchError};
+
+const HISTORY_FILE: &str = "history.jsonl";
+
+/// Verdict of the puzzle server on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+}
+
+/// Submitted answer, as recorded in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Posts answers, refusing the ones the history already proves wrong.
+#[derive(Debug, Clone)]
+pub struct Submitter {
+    pub base_url: String,
+    pub session: Option<String>,
+    /// JSON lines file of every attempt, appended after each submission.
+    pub history_path: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The answer was not sent, as the history tells the server would reject it.
+    Refused(String),
+    UnexpectedResponse(String),
+    InvalidHistory {
+        path: PathBuf,
+        message: String,
+    },
+    Client(FetchError),
+}
+
+impl Submitter {
+    /// Submitter configured like [`fetch::Fetcher::from_environment`], keeping the history of
+    /// each server apart in `~/.local/share/aoc`.
+    pub fn from_environment(base_url: Option<String>) -> Result<Submitter, SubmitError> {
+        let config = Config::load()?;
+        let base_url = config.base_url(base_url);
+        let history_path = fetch::data_dir()
+            .join(fetch::endpoint_directory(&base_url))
+            .join(HISTORY_FILE);
+
+        Ok(Submitter {
+            base_url,
+            session: config.session(),
+            history_path,
+        })
+    }
+
+    /// Posts the answer of a part, and returns the verdict with the message of the server.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<(Verdict, String), SubmitError> {
+        let history = self.history()?;
+        let attempts: Vec<&Attempt> = history
+            .iter()
+            .filter(|attempt| attempt.year == year && attempt.day == day && attempt.part == part)
+            .collect();
+        if let Some(reason) = refusal(&attempts, answer) {
+            return Err(SubmitError::Refused(reason));
+        }
+
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = format!(
+            "{}/{year}/day/{day}/answer",
+            self.base_url.trim_end_matches('/')
+        );
+        let http_error = |message: String| FetchError::Http {
+            url: url.clone(),
+            message,
+        };
+
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", fetch::USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| match error {
+                ureq::Error::Status(status, response) => {
+                    http_error(format!("status {status} {}", response.status_text()))
+                }
+                ureq::Error::Transport(transport) => {
+                    http_error(fetch::describe_transport(&transport))
+                }
+            })?
+            .into_string()
+            .map_err(|error| http_error(error.to_string()))?;
+
+        let message = article_text(&response);
+        let verdict = parse_verdict(&message)
+            .ok_or_else(|| SubmitError::UnexpectedResponse(message.clone()))?;
+
+        self.record(Attempt {
+            year,
+            day,
+            part,
+            answer: String::from(answer),
+            verdict,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_secs()),
+        })?;
+        Ok((verdict, message))
+    }
+
+    /// Every recorded attempt, oldest first.
+    pub fn history(&self) -> Result<Vec<Attempt>, SubmitError> {
+        if !self.history_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.history_path)
+            .map_err(|error| fetch::io_error(&self.history_path, error))?;
+        content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|error| SubmitError::InvalidHistory {
+                    path: self.history_path.clone(),
+                    message: error.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Appends the attempt as one line of the history file.
+    fn record(&self, attempt: Attempt) -> Result<(), SubmitError> {
+        let mut line = serde_json::to_string(&attempt).unwrap();
+        line.push('\n');
+
+        if let Some(directory) = self.history_path.parent() {
+            fs::create_dir_all(directory).map_err(|error| fetch::io_error(directory, error))?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.history_path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|error| fetch::io_error(&self.history_path, error))?;
+        Ok(())
+    }
+}
+
+/// Why the answer should not be sent, given the previous attempts on the same part: it was
+/// already rejected, the part is already solved, or it is out of the bounds of the answers
+/// found too high or too low.
+fn refusal(attempts: &[&Attempt], answer: &str) -> Option<String> {
+    if let Some(correct) = attempts
+        .iter()
+        .find(|attempt| attempt.verdict == Verdict::Correct)
+    {
+        return Some(format!("already solved with {}", correct.answer));
+    }
+
+    if let Some(attempt) = attempts.iter().find(|attempt| {
+        attempt.answer == answer
+            && matches!(
+                attempt.verdict,
+                Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+            )
+    }) {
+        return Some(format!("{answer} was already {}", attempt.verdict));
+    }
+
+    let value = answer.parse::<i128>().ok()?;
+    attempts.iter().find_map(|attempt| {
+        let bound = attempt.answer.parse::<i128>().ok()?;
+        match attempt.verdict {
+            Verdict::TooHigh if value >= bound => {
+                Some(format!("{answer} is not below {bound}, which was too high"))
+            }
+            Verdict::TooLow if value <= bound => {
+                Some(format!("{answer} is not above {bound}, which was too low"))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Text of the `<article>` of a response page, without its tags.
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn parse_verdict(message: &str) -> Option<Verdict> {
+    let message = message.to_lowercase();
+
+    if message.contains("that's the right answer") {
+        Some(Verdict::Correct)
+    } else if message.contains("you gave an answer too recently") {
+        Some(Verdict::RateLimited)
+    } else if message.contains("your answer is too high") {
+        Some(Verdict::TooHigh)
+    } else if message.contains("your answer is too low") {
+        Some(Verdict::TooLow)
+    } else if message.contains("that's not the right answer") {
+        Some(Verdict::Wrong)
+    } else if message.contains("you don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+impl From<FetchError> for SubmitError {
+    fn from(error: FetchError) -> SubmitError {
+        SubmitError::Client(error)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited => write!(f, "rate limited"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(reason) => write!(f, "Not submitted: {reason}"),
+            SubmitError::UnexpectedResponse(message) => {
+                write!(f, "Unexpected response: {message}")
+            }
+            SubmitError::InvalidHistory { path, message } => {
+                write!(f, "Invalid history {}: {message}", path.display())
+            }
+            SubmitError::Client(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::{serve, temporary_dir};
+
+    const TOO_HIGH_PAGE: &str = "<html><main><article><p>That's not the right answer; \
+        your answer is too high.  Please wait one minute.</p></article></main></html>";
+
+    fn submitter(base_url: String, name: &str) -> Submitter {
+        Submitter {
+            base_url,
+            session: Some(String::from("token")),
+            history_path: temporary_dir(name).join(HISTORY_FILE),
+        }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            parse_verdict(&article_text(TOO_HIGH_PAGE)),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            parse_verdict("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            parse_verdict("You gave an answer too recently. You have 42s left to wait."),
+            Some(Verdict::RateLimited)
+        );
+        assert_eq!(parse_verdict("Hello"), None);
+    }
+
+    #[test]
+    fn test_submit_records_and_refuses() {
+        let (base_url, requests) = serve(TOO_HIGH_PAGE);
+        let submitter = submitter(base_url, "submit-refuses");
+
+        let (verdict, message) = submitter.submit(2022, 1, 2, "1000").unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+        assert!(message.starts_with("That's not the right answer;"));
+
+        assert!(matches!(
+            submitter.submit(2022, 1, 2, "1000"),
+            Err(SubmitError::Refused(_))
+        ));
+        assert!(matches!(
+            submitter.submit(2022, 1, 2, "1200"),
+            Err(SubmitError::Refused(_))
+        ));
+        assert_eq!(
+            submitter.submit(2022, 1, 1, "1000").unwrap().0,
+            Verdict::TooHigh
+        );
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "POST /2022/day/1/answer HTTP/1.1\nCookie: session=token\n\nlevel=2&answer=1000",
+                "POST /2022/day/1/answer HTTP/1.1\nCookie: session=token\n\nlevel=1&answer=1000",
+            ]
+        );
+        let history = submitter.history().unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!((history[0].part, history[0].verdict), (2, Verdict::TooHigh));
+
+        fs::remove_dir_all(submitter.history_path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_record_appends() {
+        let (base_url, _) = serve(TOO_HIGH_PAGE);
+        let submitter = submitter(base_url, "submit-appends");
+        let previous = "{\"year\":2022,\"day\":1,\"part\":1,\"answer\":\"5\",\
+            \"verdict\":\"too_low\",\"timestamp\":0}\n";
+        fetch::write(&submitter.history_path, previous).unwrap();
+
+        submitter.submit(2022, 1, 1, "1000").unwrap();
+
+        let content = fs::read_to_string(&submitter.history_path).unwrap();
+        assert!(content.starts_with(previous));
+        assert_eq!(content.lines().count(), 2);
+        fs::remove_dir_all(submitter.history_path.parent().unwrap()).unwrap();
+    }
+}
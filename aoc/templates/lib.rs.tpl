@@ -0,0 +1,22 @@
+use aoc_core::{parse, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse::lines(input)
+            .map(|line| String::from(line.text))
+            .collect())
+    }
+
+    fn part1(lines: &Self::Input) -> impl Display {
+        lines.len()
+    }
+
+    fn part2(lines: &Self::Input) -> impl Display {
+        lines.len()
+    }
+}
@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_core::run::<{crate_ident}::Day>(&aoc_core::input!())
+}
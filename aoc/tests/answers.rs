@@ -0,0 +1,68 @@
+//! Golden answers: every Rust day is run against its inputs, and the answers are compared
+//! with the ones of its `answers.toml` manifest.
+
+use std::thread;
+
+use aoc::days::{Day, Implementation, DAYS};
+use aoc_core::{answers, Input};
+
+#[test]
+fn test_example_answers() {
+    check_answers("example.txt");
+}
+
+#[test]
+#[ignore = "slow without optimizations, run with `cargo test --release -p aoc -- --ignored`"]
+fn test_real_answers() {
+    check_answers("real.txt");
+}
+
+/// Runs every day having the given input file, and fails listing every changed answer.
+fn check_answers(file_name: &str) {
+    let failures: Vec<String> = thread::scope(|scope| {
+        let handles: Vec<_> = DAYS
+            .iter()
+            .map(|day| scope.spawn(move || check_day(day, file_name)))
+            .collect();
+
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    assert!(
+        failures.is_empty(),
+        "{} answer(s) changed:\n\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}
+
+fn check_day(day: &Day, file_name: &str) -> Option<String> {
+    let Implementation::Rust(solve) = day.implementation else {
+        return None;
+    };
+
+    let directory = day.directory();
+    let path = directory.join(file_name);
+    if !path.exists() {
+        return None;
+    }
+
+    let label = format!("{} day {:02}, {file_name}", day.year, day.day);
+    let Some(expected) =
+        answers::load_manifest(&directory).and_then(|mut manifest| manifest.remove(file_name))
+    else {
+        return Some(format!(
+            "{label}: no expected answers in {}",
+            answers::MANIFEST_FILE
+        ));
+    };
+
+    let (actual, _) = match solve(Input::from_path(&path).as_str()) {
+        Ok(solved) => solved,
+        Err(error) => return Some(format!("{label}:\n{}", error.with_file(path))),
+    };
+    answers::diff(&expected, &actual).map(|difference| format!("{label}, {difference}"))
+}